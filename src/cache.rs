@@ -0,0 +1,76 @@
+use crate::Rad;
+use num_traits::{
+    cast::FromPrimitive,
+    float::{Float, FloatConst},
+};
+
+/// An angle together with its sine and cosine, which are only
+/// recomputed when the angle changes.
+///
+/// Useful in hot loops that evaluate the same angles every frame.
+/// Changes smaller than [`Rad::approx_eq`] can detect keep the cached values.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct AngleCache<N: Float> {
+    angle: Rad<N>,
+    sin: N,
+    cos: N,
+}
+
+impl<N> AngleCache<N>
+where
+    N: Float + FromPrimitive + FloatConst,
+{
+    pub fn new<A: Into<Rad<N>>>(angle: A) -> AngleCache<N> {
+        let angle = angle.into();
+        let (sin, cos) = angle.value().sin_cos();
+        AngleCache { angle, sin, cos }
+    }
+
+    #[inline]
+    pub fn angle(&self) -> Rad<N> {
+        self.angle
+    }
+
+    #[inline]
+    pub fn sin(&self) -> N {
+        self.sin
+    }
+
+    #[inline]
+    pub fn cos(&self) -> N {
+        self.cos
+    }
+
+    /// Update the angle, recomputing the sine and cosine only if it
+    /// changed. Returns whether they were recomputed.
+    pub fn set<A: Into<Rad<N>>>(&mut self, angle: A) -> bool {
+        let angle = angle.into();
+        if self.angle.approx_eq(angle) {
+            return false;
+        }
+        *self = AngleCache::new(angle);
+        true
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Deg;
+
+    #[test]
+    fn test_recompute() {
+        let mut cache = AngleCache::new(Deg(90_f64));
+        assert_eq!(cache.sin(), 1.);
+        assert!(cache.cos().abs() < 1e-15);
+
+        assert!(!cache.set(Deg(90.)));
+        assert!(!cache.set(std::f64::consts::FRAC_PI_2));
+        assert_eq!(cache.sin(), 1.);
+
+        assert!(cache.set(Deg(0.)));
+        assert_eq!(cache.angle(), Rad(0.));
+        assert_eq!(cache.sin(), 0.);
+        assert_eq!(cache.cos(), 1.);
+    }
+}
@@ -0,0 +1,104 @@
+//! Common angles as concrete `f32` and `f64` constants.
+//!
+//! The generic types can't be built from constants in a `const`
+//! context, so these are provided for `const` and `static` use.
+//!
+//! ```
+//! use angle::{consts, Deg};
+//!
+//! static HEADING: Deg<f64> = consts::DEG_90_F64;
+//! assert!(HEADING.approx_eq(consts::RAD_FRAC_PI_2_F64));
+//! ```
+use crate::{Deg, Rad};
+
+/// 0° as `Deg<f32>`.
+pub const DEG_0_F32: Deg<f32> = Deg(0.0);
+/// 45° as `Deg<f32>`.
+pub const DEG_45_F32: Deg<f32> = Deg(45.0);
+/// 90° as `Deg<f32>`.
+pub const DEG_90_F32: Deg<f32> = Deg(90.0);
+/// 180° as `Deg<f32>`.
+pub const DEG_180_F32: Deg<f32> = Deg(180.0);
+/// 270° as `Deg<f32>`.
+pub const DEG_270_F32: Deg<f32> = Deg(270.0);
+/// 360° as `Deg<f32>`.
+pub const DEG_360_F32: Deg<f32> = Deg(360.0);
+
+/// 0 radians as `Rad<f32>`.
+pub const RAD_0_F32: Rad<f32> = Rad(0.0);
+/// π/4 radians as `Rad<f32>`.
+pub const RAD_FRAC_PI_4_F32: Rad<f32> = Rad(std::f32::consts::FRAC_PI_4);
+/// π/2 radians as `Rad<f32>`.
+pub const RAD_FRAC_PI_2_F32: Rad<f32> = Rad(std::f32::consts::FRAC_PI_2);
+/// π radians as `Rad<f32>`.
+pub const RAD_PI_F32: Rad<f32> = Rad(std::f32::consts::PI);
+/// 3π/2 radians as `Rad<f32>`.
+pub const RAD_3_FRAC_PI_2_F32: Rad<f32> = Rad(std::f32::consts::PI + std::f32::consts::FRAC_PI_2);
+/// 2π radians as `Rad<f32>`.
+pub const RAD_TAU_F32: Rad<f32> = Rad(std::f32::consts::TAU);
+
+/// 0° as `Deg<f64>`.
+pub const DEG_0_F64: Deg<f64> = Deg(0.0);
+/// 45° as `Deg<f64>`.
+pub const DEG_45_F64: Deg<f64> = Deg(45.0);
+/// 90° as `Deg<f64>`.
+pub const DEG_90_F64: Deg<f64> = Deg(90.0);
+/// 180° as `Deg<f64>`.
+pub const DEG_180_F64: Deg<f64> = Deg(180.0);
+/// 270° as `Deg<f64>`.
+pub const DEG_270_F64: Deg<f64> = Deg(270.0);
+/// 360° as `Deg<f64>`.
+pub const DEG_360_F64: Deg<f64> = Deg(360.0);
+
+/// 0 radians as `Rad<f64>`.
+pub const RAD_0_F64: Rad<f64> = Rad(0.0);
+/// π/4 radians as `Rad<f64>`.
+pub const RAD_FRAC_PI_4_F64: Rad<f64> = Rad(std::f64::consts::FRAC_PI_4);
+/// π/2 radians as `Rad<f64>`.
+pub const RAD_FRAC_PI_2_F64: Rad<f64> = Rad(std::f64::consts::FRAC_PI_2);
+/// π radians as `Rad<f64>`.
+pub const RAD_PI_F64: Rad<f64> = Rad(std::f64::consts::PI);
+/// 3π/2 radians as `Rad<f64>`.
+pub const RAD_3_FRAC_PI_2_F64: Rad<f64> = Rad(std::f64::consts::PI + std::f64::consts::FRAC_PI_2);
+/// 2π radians as `Rad<f64>`.
+pub const RAD_TAU_F64: Rad<f64> = Rad(std::f64::consts::TAU);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_values() {
+        assert_eq!(DEG_90_F32.value(), 90.);
+        assert_eq!(DEG_360_F64.value(), 360.);
+        assert_eq!(RAD_PI_F32.value(), std::f32::consts::PI);
+        assert_eq!(RAD_TAU_F64.value(), std::f64::consts::TAU);
+    }
+
+    #[test]
+    fn test_cross_unit() {
+        let f32_pairs = [
+            (DEG_0_F32, RAD_0_F32),
+            (DEG_45_F32, RAD_FRAC_PI_4_F32),
+            (DEG_90_F32, RAD_FRAC_PI_2_F32),
+            (DEG_180_F32, RAD_PI_F32),
+            (DEG_270_F32, RAD_3_FRAC_PI_2_F32),
+            (DEG_360_F32, RAD_TAU_F32),
+        ];
+        for (deg, rad) in f32_pairs {
+            assert!(rad.approx_eq(deg));
+        }
+
+        let f64_pairs = [
+            (DEG_0_F64, RAD_0_F64),
+            (DEG_45_F64, RAD_FRAC_PI_4_F64),
+            (DEG_90_F64, RAD_FRAC_PI_2_F64),
+            (DEG_180_F64, RAD_PI_F64),
+            (DEG_270_F64, RAD_3_FRAC_PI_2_F64),
+            (DEG_360_F64, RAD_TAU_F64),
+        ];
+        for (deg, rad) in f64_pairs {
+            assert!(rad.approx_eq(deg));
+        }
+    }
+}
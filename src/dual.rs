@@ -0,0 +1,115 @@
+use crate::{Deg, Rad};
+use num_traits::{
+    cast::FromPrimitive,
+    float::{Float, FloatConst},
+};
+use std::ops::{Add, Sub};
+
+/// An angle stored in both degrees and radians, so reading either unit
+/// costs no conversion.
+///
+/// The conversion happens once, on construction. Adding or subtracting
+/// works on both units side by side, so they stay in step without
+/// converting again.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct DualAngle<N: Float> {
+    deg: Deg<N>,
+    rad: Rad<N>,
+}
+
+impl<N: Float> DualAngle<N> {
+    #[inline]
+    pub fn deg(&self) -> Deg<N> {
+        self.deg
+    }
+
+    #[inline]
+    pub fn rad(&self) -> Rad<N> {
+        self.rad
+    }
+}
+
+impl<N> From<Deg<N>> for DualAngle<N>
+where
+    N: Float + FromPrimitive + FloatConst,
+{
+    #[inline]
+    fn from(deg: Deg<N>) -> DualAngle<N> {
+        DualAngle {
+            deg,
+            rad: deg.into(),
+        }
+    }
+}
+
+impl<N> From<Rad<N>> for DualAngle<N>
+where
+    N: Float + FromPrimitive + FloatConst,
+{
+    #[inline]
+    fn from(rad: Rad<N>) -> DualAngle<N> {
+        DualAngle {
+            deg: rad.into(),
+            rad,
+        }
+    }
+}
+
+impl<N: Float> Add for DualAngle<N> {
+    type Output = DualAngle<N>;
+
+    #[inline]
+    fn add(self, rhs: DualAngle<N>) -> DualAngle<N> {
+        DualAngle {
+            deg: Deg(self.deg.0 + rhs.deg.0),
+            rad: Rad(self.rad.0 + rhs.rad.0),
+        }
+    }
+}
+
+impl<N: Float> Sub for DualAngle<N> {
+    type Output = DualAngle<N>;
+
+    #[inline]
+    fn sub(self, rhs: DualAngle<N>) -> DualAngle<N> {
+        DualAngle {
+            deg: Deg(self.deg.0 - rhs.deg.0),
+            rad: Rad(self.rad.0 - rhs.rad.0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn assert_in_sync(angle: DualAngle<f64>) {
+        assert!((angle.deg().to_radians() - angle.rad().0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_construct() {
+        let from_deg = DualAngle::from(Deg(90.));
+        assert_eq!(from_deg.deg(), Deg(90.));
+        assert_eq!(from_deg.rad(), Rad(std::f64::consts::FRAC_PI_2));
+
+        let from_rad = DualAngle::from(Rad(1.25));
+        assert_eq!(from_rad.rad(), Rad(1.25));
+        assert_in_sync(from_deg);
+        assert_in_sync(from_rad);
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let a = DualAngle::from(Deg(30.));
+        let b = DualAngle::from(Rad(0.75));
+
+        let sum = a + b;
+        assert_in_sync(sum);
+        assert!((sum.deg().0 - (30. + 0.75_f64.to_degrees())).abs() < 1e-12);
+
+        let difference = a - b;
+        assert_in_sync(difference);
+        assert!((difference.rad().0 - (30_f64.to_radians() - 0.75)).abs() < 1e-12);
+    }
+}
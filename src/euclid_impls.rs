@@ -0,0 +1,63 @@
+//! Conversions to and from `euclid::Angle`, enabled with the `euclid` feature.
+//!
+//! `euclid::Angle` stores radians, so `Rad` converts directly and `Deg`
+//! goes through the usual degree conversion.
+use crate::{Deg, Rad};
+use euclid::Angle;
+use num_traits::{
+    cast::FromPrimitive,
+    float::{Float, FloatConst},
+};
+
+impl<N: Float> From<Rad<N>> for Angle<N> {
+    #[inline]
+    fn from(rad: Rad<N>) -> Angle<N> {
+        Angle::radians(rad.0)
+    }
+}
+
+impl<N: Float> From<Angle<N>> for Rad<N> {
+    #[inline]
+    fn from(angle: Angle<N>) -> Rad<N> {
+        Rad(angle.radians)
+    }
+}
+
+impl<N> From<Deg<N>> for Angle<N>
+where
+    N: Float + FromPrimitive + FloatConst,
+{
+    #[inline]
+    fn from(deg: Deg<N>) -> Angle<N> {
+        Angle::radians(deg.to_radians())
+    }
+}
+
+impl<N> From<Angle<N>> for Deg<N>
+where
+    N: Float + FromPrimitive + FloatConst,
+{
+    #[inline]
+    fn from(angle: Angle<N>) -> Deg<N> {
+        Rad(angle.radians).into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let angle: Angle<f64> = Rad(1.25).into();
+        assert_eq!(angle.radians, 1.25);
+        assert_eq!(Rad::from(angle), Rad(1.25));
+
+        let angle: Angle<f32> = Deg(90.).into();
+        assert!(Rad(angle.radians).approx_eq(Deg(90.)));
+        assert!(Deg::from(angle).approx_eq(90.));
+
+        let angle = Angle::degrees(45_f64);
+        assert!(Deg::from(angle).approx_eq(45.));
+    }
+}
@@ -0,0 +1,50 @@
+//! C-callable conversion functions, enabled with the `ffi` feature.
+//!
+//! These take and return plain floats, which is what `Deg` and `Rad`
+//! look like from C thanks to `repr(transparent)`.
+use crate::{Deg, Rad};
+
+/// Convert degrees to radians.
+#[no_mangle]
+pub extern "C" fn angle_deg_to_rad_f64(deg: f64) -> f64 {
+    Deg(deg).to_radians()
+}
+
+/// Convert radians to degrees.
+#[no_mangle]
+pub extern "C" fn angle_rad_to_deg_f64(rad: f64) -> f64 {
+    Rad(rad).to_degrees()
+}
+
+/// Convert degrees to radians.
+#[no_mangle]
+pub extern "C" fn angle_deg_to_rad_f32(deg: f32) -> f32 {
+    Deg(deg).to_radians()
+}
+
+/// Convert radians to degrees.
+#[no_mangle]
+pub extern "C" fn angle_rad_to_deg_f32(rad: f32) -> f32 {
+    Rad(rad).to_degrees()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_matches_methods() {
+        for value in [0., 45., -90., 180., 1234.5] {
+            assert_eq!(angle_deg_to_rad_f64(value), Deg(value).to_radians());
+            assert_eq!(angle_rad_to_deg_f64(value), Rad(value).to_degrees());
+            assert_eq!(
+                angle_deg_to_rad_f32(value as f32),
+                Deg(value as f32).to_radians()
+            );
+            assert_eq!(
+                angle_rad_to_deg_f32(value as f32),
+                Rad(value as f32).to_degrees()
+            );
+        }
+    }
+}
@@ -0,0 +1,61 @@
+//! Half precision angles, enabled with the `half` feature.
+//!
+//! The feature turns on the `num-traits` glue in the `half` crate, so
+//! `Deg<f16>` and `Rad<f16>` get every generic method. `f16` carries only
+//! about three significant decimal digits, so 359.9° is stored as 360°
+//! and conversions are good to roughly 1e-3 relative. Widen to `f32` for
+//! any long chain of arithmetic.
+use crate::{Deg, Rad};
+use half::f16;
+
+impl Deg<f16> {
+    /// Widen to `f32`, which is exact.
+    #[inline]
+    pub fn to_f32(&self) -> Deg<f32> {
+        Deg(self.0.to_f32())
+    }
+
+    /// Narrow from `f32`, rounding to the nearest `f16`.
+    #[inline]
+    pub fn from_f32(deg: Deg<f32>) -> Deg<f16> {
+        Deg(f16::from_f32(deg.0))
+    }
+}
+
+impl Rad<f16> {
+    /// Widen to `f32`, which is exact.
+    #[inline]
+    pub fn to_f32(&self) -> Rad<f32> {
+        Rad(self.0.to_f32())
+    }
+
+    /// Narrow from `f32`, rounding to the nearest `f16`.
+    #[inline]
+    pub fn from_f32(rad: Rad<f32>) -> Rad<f16> {
+        Rad(f16::from_f32(rad.0))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_conversions() {
+        let deg = Deg(f16::from_f32(90.));
+        let rad: Rad<f16> = deg.into();
+        assert!((rad.to_f32().0 - std::f32::consts::FRAC_PI_2).abs() < 2e-3);
+
+        let back: Deg<f16> = rad.into();
+        assert!((back.to_f32().0 - 90.).abs() < 0.1);
+        assert!(back.approx_eq_wrapped(deg, f16::from_f32(0.1)));
+    }
+
+    #[test]
+    fn test_widen_narrow() {
+        assert_eq!(Deg::from_f32(Deg(45.25)).to_f32(), Deg(45.25));
+        assert_eq!(Deg::from_f32(Deg(359.9)).to_f32(), Deg(360.));
+        assert!((Rad::from_f32(Rad(1.2345)).to_f32().0 - 1.2345).abs() < 1e-3);
+        assert_eq!(Deg(f16::from_f32(450.)).normalized().to_f32(), Deg(90.));
+    }
+}
@@ -21,7 +21,131 @@ use num_traits::{
     cast::FromPrimitive,
     float::{Float, FloatConst},
 };
-use std::fmt;
+use std::{cmp::Ordering, error::Error, fmt, str::FromStr};
+
+pub mod consts;
+pub mod lookup;
+pub mod transform;
+pub mod unit;
+
+mod cache;
+mod dual;
+mod range;
+mod velocity;
+mod wrapping;
+pub use cache::AngleCache;
+pub use dual::DualAngle;
+pub use range::DegRange;
+pub use velocity::{DegPerSec, RadPerSec};
+pub use wrapping::Wrapping;
+
+#[cfg(feature = "ffi")]
+pub mod ffi;
+
+#[cfg(feature = "euclid")]
+mod euclid_impls;
+
+#[cfg(feature = "half")]
+mod half_impls;
+
+#[cfg(feature = "serde")]
+mod serde_impls;
+
+/// Wrap a value into the range [0, full).
+///
+/// A zero result is always positive zero, even for `-0.0` or negative
+/// multiples of a full turn, so wrapped angles are bit-equal whenever
+/// they are equal. That keeps them consistent as hash keys or when
+/// serialized.
+#[inline]
+fn wrap<N: Float>(value: N, full: N) -> N {
+    let value = value % full;
+    let value = if value < N::zero() {
+        value + full
+    } else {
+        value
+    };
+
+    // Adding a full turn to a tiny negative remainder can round up to it,
+    // and `%` keeps the sign of a negative zero.
+    if value >= full || value == N::zero() {
+        N::zero()
+    } else {
+        value
+    }
+}
+
+/// Map the bits of a float onto integers so that adjacent floats are
+/// adjacent integers, with both zeros at 0.
+#[inline]
+fn ordered_bits_f32(value: f32) -> i32 {
+    let bits = value.to_bits() as i32;
+    if bits < 0 {
+        i32::MIN.wrapping_sub(bits)
+    } else {
+        bits
+    }
+}
+
+/// Map the bits of a float onto integers so that adjacent floats are
+/// adjacent integers, with both zeros at 0.
+#[inline]
+fn ordered_bits_f64(value: f64) -> i64 {
+    let bits = value.to_bits() as i64;
+    if bits < 0 {
+        i64::MIN.wrapping_sub(bits)
+    } else {
+        bits
+    }
+}
+
+/// Build a whole number out of ones by doubling and adding.
+///
+/// Unlike `FromPrimitive::from_f64` this can't fail, so the conversions
+/// never have to unwrap, whatever the float type. It's meant for the small
+/// constants used by this crate, like 360, which `f32` and `f64` hold
+/// exactly, so the result is identical to a cast. Past 2^24 for `f32`
+/// the steps round, so convert runtime values with `FromPrimitive`.
+#[inline]
+fn whole<N: Float>(n: u32) -> N {
+    let two = N::one() + N::one();
+    (0..u32::BITS).rev().fold(N::zero(), |acc, bit| {
+        let acc = acc * two;
+        if (n >> bit) & 1 == 1 {
+            acc + N::one()
+        } else {
+            acc
+        }
+    })
+}
+
+/// Convert a count or index to a float, saturating to the largest finite
+/// value if the type can't represent it at all. For `f32` and `f64` this
+/// is the same as a cast, rounding beyond 2^24 and 2^53.
+#[inline]
+fn float_count<N: Float + FromPrimitive>(n: usize) -> N {
+    N::from_usize(n).unwrap_or_else(N::max_value)
+}
+
+/// Convert a whole float to an integer, saturating the same way as an
+/// `as` cast: values out of range clamp to the integer's limits and NaN
+/// gives 0.
+#[inline]
+fn saturating_int<N: Float, I: num_traits::PrimInt>(value: N) -> I {
+    I::from(value).unwrap_or(if value.is_nan() {
+        I::zero()
+    } else if value > N::zero() {
+        I::max_value()
+    } else {
+        I::min_value()
+    })
+}
+
+/// Names of the 16 compass points, clockwise from north.
+const COMPASS_POINTS: [&str; 16] = [
+    "N", "NNE", "NE", "ENE", "E", "ESE", "SE", "SSE", "S", "SSW", "SW", "WSW", "W", "WNW", "NW",
+    "NNW",
+];
 
 // NOTE: repr(transparent) is for C ffi, required so
 //       Rust will use the correct C calling conventions.
@@ -31,6 +155,8 @@ use std::fmt;
 //       in different registers on some platforms.
 
 #[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
 #[repr(transparent)]
 pub struct Deg<N: Float>(pub N);
 
@@ -45,187 +171,3215 @@ where
 
     #[inline]
     pub fn to_radians(&self) -> N {
-        let d: N = N::from_f64(180.).unwrap();
+        let d: N = whole(180);
         let pi = N::PI();
         self.0 * (pi / d)
     }
 
+    /// Approximate equality within the machine epsilon of `N`.
+    ///
+    /// The tolerance depends on the float type, so `f32` angles compare
+    /// far more loosely than `f64` ones. Use `approx_eq_as_f64` for the
+    /// same tolerance regardless of `N`.
     #[inline]
     pub fn approx_eq<T: Into<Self>>(&self, rhs: T) -> bool {
         (self.0 - rhs.into().0).abs() < Float::epsilon()
     }
-}
 
-impl<N> Into<Rad<N>> for Deg<N>
-where
-    N: Float + FromPrimitive + FloatConst,
-{
-    fn into(self) -> Rad<N> {
-        Rad(self.to_radians())
+    /// Round to the nearest whole degree, away from zero on a tie.
+    #[inline]
+    pub fn round(&self) -> Deg<N> {
+        Deg(self.0.round())
     }
-}
 
-/// Convert float to degrees.
-impl<N: Float> From<N> for Deg<N> {
+    /// Largest whole degree less than or equal to the angle.
     #[inline]
-    fn from(value: N) -> Deg<N> {
-        Deg(value)
+    pub fn floor(&self) -> Deg<N> {
+        Deg(self.0.floor())
     }
-}
 
-impl<N> fmt::Display for Deg<N>
-where
-    N: Float + fmt::Display,
-{
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "({})", self.0)
+    /// Smallest whole degree greater than or equal to the angle.
+    #[inline]
+    pub fn ceil(&self) -> Deg<N> {
+        Deg(self.0.ceil())
     }
-}
 
-#[derive(Copy, Clone, Debug, PartialEq)]
-#[repr(transparent)]
-pub struct Rad<N: Float>(pub N);
+    /// Whole degree part of the angle, truncated towards zero.
+    #[inline]
+    pub fn trunc(&self) -> Deg<N> {
+        Deg(self.0.trunc())
+    }
 
-impl<N> Rad<N>
-where
-    N: Float + FromPrimitive + FloatConst,
-{
+    /// Mean direction of the given angles, found by summing their
+    /// unit vectors, so that the mean of 350° and 10° is 0° and not 180°.
+    ///
+    /// The result is in the range (-180°, 180°]. Returns `None` for an
+    /// empty slice, or when the angles cancel out and there is no
+    /// defined mean direction, like 0° and 180°.
+    pub fn circular_mean(angles: &[Deg<N>]) -> Option<Deg<N>> {
+        if angles.is_empty() {
+            return None;
+        }
+
+        let count = N::from_usize(angles.len())?;
+        let (sin, cos) = angles
+            .iter()
+            .fold((N::zero(), N::zero()), |(sin, cos), angle| {
+                let (s, c) = angle.to_radians().sin_cos();
+                (sin + s, cos + c)
+            });
+
+        if sin.hypot(cos) <= N::epsilon() * count {
+            return None;
+        }
+
+        Some(Rad(sin.atan2(cos)).into())
+    }
+
+    /// Wrap the angle into the range [0°, 360°).
+    ///
+    /// Zero is always returned as positive zero, so `Deg(-0.0)` and
+    /// `Deg(-360.0)` normalize to the same bits as `Deg(0.0)`.
     #[inline]
-    pub fn value(&self) -> N {
-        self.0
+    pub fn normalized(&self) -> Deg<N> {
+        Deg(wrap(self.0, whole(360)))
     }
 
+    /// Wrap the angle into the range [-180°, 180°).
     #[inline]
-    pub fn to_degrees(&self) -> N {
-        let d: N = N::from_f64(180.).unwrap();
-        let pi = N::PI();
-        self.0 * (d / pi)
+    pub fn normalized_signed(&self) -> Deg<N> {
+        let half: N = whole(180);
+        Deg(wrap(self.0 + half, whole(360)) - half)
+    }
+
+    /// Order two angles by their counterclockwise distance from `reference`,
+    /// so a fan of angles can be sorted around the circle regardless of
+    /// where it crosses 0°.
+    ///
+    /// Angles that can't be compared, like NaN, are treated as equal.
+    pub fn cmp_around(&self, other: &Deg<N>, reference: Deg<N>) -> Ordering {
+        let lhs = Deg(self.0 - reference.0).normalized();
+        let rhs = Deg(other.0 - reference.0).normalized();
+        lhs.0.partial_cmp(&rhs.0).unwrap_or(Ordering::Equal)
     }
 
+    /// Angle swept by rotating at a constant `deg_per_sec` for `elapsed` seconds.
+    ///
+    /// The result is not wrapped, so it can be used to count whole turns.
+    /// Call [`normalized`](Deg::normalized) on it to get a heading.
     #[inline]
-    pub fn approx_eq<T: Into<Self>>(&self, rhs: T) -> bool {
-        (self.0 - rhs.into().0).abs() < Float::epsilon()
+    pub fn from_rate(deg_per_sec: N, elapsed: N) -> Deg<N> {
+        Deg(deg_per_sec * elapsed)
     }
-}
 
-impl<N> Into<Deg<N>> for Rad<N>
-where
-    N: Float + FromPrimitive + FloatConst,
-{
+    /// Convert a math angle (counterclockwise, Y up) to a screen angle
+    /// (clockwise, Y down), wrapped into [0°, 360°).
     #[inline]
-    fn into(self) -> Deg<N> {
-        Deg(self.to_degrees())
+    pub fn to_screen(&self) -> Deg<N> {
+        Deg(-self.0).normalized()
     }
-}
 
-/// Convert float to radians.
-impl<N: Float> From<N> for Rad<N> {
+    /// Convert a screen angle (clockwise, Y down) to a math angle
+    /// (counterclockwise, Y up), wrapped into [0°, 360°).
     #[inline]
-    fn from(value: N) -> Rad<N> {
-        Rad(value)
+    pub fn from_screen(screen: Deg<N>) -> Deg<N> {
+        Deg(-screen.0).normalized()
     }
-}
 
-impl<N> fmt::Display for Rad<N>
-where
-    N: Float + fmt::Display,
-{
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "({})", self.0)
+    /// Approximate equality using a tolerance relative to the larger
+    /// magnitude, `|a - b| <= rel * max(|a|, |b|)`, which stays meaningful
+    /// for large angles where [`approx_eq`](Deg::approx_eq) is too strict.
+    #[inline]
+    pub fn approx_eq_rel<T: Into<Self>>(&self, rhs: T, rel: N) -> bool {
+        let rhs = rhs.into().0;
+        (self.0 - rhs).abs() <= rel * self.0.abs().max(rhs.abs())
     }
-}
 
-/// Approximate equality comparison for floating point numbers.
-#[macro_export]
-macro_rules! inexact_eq {
-    ($lhs:expr, $rhs:expr) => {
-        (f64::from($lhs) - f64::from($rhs)).abs() < std::f64::EPSILON
-    };
-}
+    /// The angle as a fraction of a full circle, in the range [0, 1).
+    ///
+    /// The angle is normalized first, so `Deg(90.)` and `Deg(450.)` are both `0.25`.
+    #[inline]
+    pub fn as_fraction(&self) -> N {
+        self.normalized().0 / whole(360)
+    }
 
-#[cfg(test)]
-mod test {
-    use super::*;
-    use crate::inexact_eq;
+    /// Angle covering the given fraction of a full circle.
+    #[inline]
+    pub fn from_fraction(fraction: N) -> Deg<N> {
+        Deg(fraction * whole(360))
+    }
 
-    #[test]
-    fn test_degrees() {
-        let deg_45 = Deg(45.);
-        let rad_45 = ::std::f32::consts::PI / 4.;
-        assert!(inexact_eq!(rad_45, deg_45.to_radians()));
-        assert!(Rad(rad_45).approx_eq(deg_45));
+    /// Signed rotation along the shortest path from this angle to
+    /// `other`, in the range [-180°, 180°). Positive is counterclockwise.
+    #[inline]
+    pub fn delta_to(&self, other: Deg<N>) -> Deg<N> {
+        Deg(other.0 - self.0).normalized_signed()
     }
 
-    #[test]
-    fn test_radians() {
-        let pi = ::std::f32::consts::PI;
-        let deg_45 = 45.;
-        let rad_45 = Rad(pi / 4.);
-        assert!(inexact_eq!(deg_45, rad_45.to_degrees()));
-        assert!(Deg(deg_45).approx_eq(rad_45));
+    /// Approximate equality on the circle, comparing the shortest
+    /// distance between the angles against `eps`, so that 0° and
+    /// 359.9999° are considered equal.
+    #[inline]
+    pub fn approx_eq_wrapped<T: Into<Self>>(&self, rhs: T, eps: N) -> bool {
+        self.delta_to(rhs.into()).0.abs() <= eps
     }
 
-    /// Using a float directly as degrees or rads.
-    #[test]
-    fn test_info() {
-        fn sum_f32<T: Into<Deg<f32>>>(lhs: T, rhs: T) -> Deg<f32> {
-            Deg(lhs.into().value() + rhs.into().value())
+    /// Returns `true` if the angle is NaN.
+    #[inline]
+    pub fn is_nan(&self) -> bool {
+        self.0.is_nan()
+    }
+
+    /// Returns `true` if the angle is positive or negative infinity.
+    #[inline]
+    pub fn is_infinite(&self) -> bool {
+        self.0.is_infinite()
+    }
+
+    /// Returns `true` if the angle is neither infinite nor NaN.
+    #[inline]
+    pub fn is_finite(&self) -> bool {
+        self.0.is_finite()
+    }
+
+    /// Angle of a slope with the given rise over run, measured from the
+    /// horizontal. A vertical slope with zero run is ±90°, following the
+    /// sign of the rise.
+    #[inline]
+    pub fn from_slope(rise: N, run: N) -> Deg<N> {
+        Rad::from_slope(rise, run).into()
+    }
+
+    /// The nearest of the 16 compass points to this bearing, from "N"
+    /// clockwise through "NNE", "NE" and so on.
+    ///
+    /// Each point covers a 22.5° sector centred on it. A NaN angle maps to "N".
+    pub fn to_compass_point(&self) -> &'static str {
+        let sector: N = whole::<N>(45) / whole(2);
+        let index = ((self.normalized().0 + sector / whole(2)) / sector).floor();
+        COMPASS_POINTS[index.to_usize().unwrap_or(0) % COMPASS_POINTS.len()]
+    }
+
+    /// Bearing of one of the 16 compass points, like "NNE", ignoring case.
+    ///
+    /// Returns `None` if the name isn't a compass point.
+    pub fn from_compass_point(name: &str) -> Option<Deg<N>> {
+        let sector: N = whole::<N>(45) / whole(2);
+        COMPASS_POINTS
+            .iter()
+            .zip(0..)
+            .find(|(point, _)| point.eq_ignore_ascii_case(name))
+            .map(|(_, index)| Deg(sector * whole(index)))
+    }
+
+    /// Wrap the angle into [-180°, 180°) and then clamp it to `[lo, hi]`,
+    /// like a servo with mechanical limits.
+    ///
+    /// Wrapping first means 350° is treated as -10° rather than being
+    /// clamped to the upper limit.
+    #[inline]
+    pub fn wrap_then_clamp(&self, lo: Deg<N>, hi: Deg<N>) -> Deg<N> {
+        Deg(self.normalized_signed().0.max(lo.0).min(hi.0))
+    }
+
+    /// Quaternion `[x, y, z, w]` for a rotation of this angle about the Z axis.
+    #[inline]
+    pub fn to_quat_z(&self) -> [N; 4] {
+        Rad::from(*self).to_quat_z()
+    }
+
+    /// Total signed turning along a sequence of headings, summing the
+    /// shortest rotation between each consecutive pair.
+    ///
+    /// Unlike the headings themselves this isn't wrapped, so a path that
+    /// loops around once and a half winds 540°.
+    pub fn total_winding(angles: &[Deg<N>]) -> Deg<N> {
+        Deg(angles
+            .windows(2)
+            .fold(N::zero(), |total, pair| total + pair[0].delta_to(pair[1]).0))
+    }
+
+    /// Angle of an incline given as a percentage grade, rise over run
+    /// times 100, so a 100% grade is 45°.
+    #[inline]
+    pub fn from_percent_grade(pct: N) -> Deg<N> {
+        Rad((pct / whole(100)).atan()).into()
+    }
+
+    /// Percentage grade of an incline at this angle, rise over run times 100.
+    #[inline]
+    pub fn to_percent_grade(&self) -> N {
+        self.to_radians().tan() * whole(100)
+    }
+
+    /// The target closest to this angle along the shortest arc, taking
+    /// wraparound into account. Ties go to the earlier target.
+    ///
+    /// Returns `None` if there are no targets.
+    pub fn nearest_of(&self, targets: &[Deg<N>]) -> Option<Deg<N>> {
+        targets.iter().copied().min_by(|a, b| {
+            let a = self.delta_to(*a).0.abs();
+            let b = self.delta_to(*b).0.abs();
+            a.partial_cmp(&b).unwrap_or(Ordering::Equal)
+        })
+    }
+
+    /// `steps` evenly spaced angles from this angle to `end` inclusive,
+    /// along the shortest path and wrapped into [0°, 360°).
+    ///
+    /// Zero steps gives no angles and a single step gives just the start.
+    pub fn interpolate_steps(&self, end: Deg<N>, steps: usize) -> Vec<Deg<N>> {
+        if steps <= 1 {
+            return (0..steps).map(|_| self.normalized()).collect();
         }
-        assert_eq!(sum_f32(Deg(45.), Deg(75.)), Deg(120.));
-        assert_eq!(sum_f32(45., 75.), Deg(120.));
+
+        let delta = self.delta_to(end).0;
+        let last: N = float_count(steps - 1);
+        (0..steps)
+            .map(|i| {
+                let t = float_count::<N>(i) / last;
+                Deg(self.0 + delta * t).normalized()
+            })
+            .collect()
     }
 
-    #[test]
-    fn test_non_generic() {
-        type Vector = [f64; 2];
+    /// Fused `self * mul + add`, rounded once using the float's `mul_add`.
+    #[inline]
+    pub fn mul_add(&self, mul: N, add: Deg<N>) -> Deg<N> {
+        Deg(self.0.mul_add(mul, add.0))
+    }
 
-        /// Rotate vector counterclockwise by the given angle
-        fn rotate(a: Vector, angle: Deg<f64>) -> Vector {
-            let r = angle.to_radians();
-            let x = a[0] * f64::cos(r) - a[1] * f64::sin(r);
-            let y = a[0] * f64::sin(r) + a[1] * f64::cos(r);
-            [x, y]
+    /// Exterior angle of a polygon corner with the given interior angle.
+    #[inline]
+    pub fn exterior_of(interior: Deg<N>) -> Deg<N> {
+        Deg(whole::<N>(180) - interior.0)
+    }
+
+    /// Interior angle of a regular polygon, `(n - 2) * 180° / n`.
+    ///
+    /// Only meaningful for three or more sides.
+    #[inline]
+    pub fn regular_interior(sides: u32) -> Deg<N> {
+        let n: N = float_count(sides as usize);
+        Deg((n - whole(2)) * whole(180) / n)
+    }
+
+    /// Exterior angle of a regular polygon, `360° / n`.
+    ///
+    /// Only meaningful for three or more sides.
+    #[inline]
+    pub fn regular_exterior(sides: u32) -> Deg<N> {
+        Deg(whole::<N>(360) / float_count(sides as usize))
+    }
+
+    /// Distance turning only counterclockwise from this angle to `other`,
+    /// in the range [0°, 360°).
+    #[inline]
+    pub fn ccw_distance_to(&self, other: Deg<N>) -> Deg<N> {
+        Deg(other.0 - self.0).normalized()
+    }
+
+    /// Distance turning only clockwise from this angle to `other`,
+    /// in the range [0°, 360°).
+    #[inline]
+    pub fn cw_distance_to(&self, other: Deg<N>) -> Deg<N> {
+        Deg(self.0 - other.0).normalized()
+    }
+
+    /// The acute angle between the terminal side and the x-axis, in the
+    /// range [0°, 90°], so both 150° and 210° have a reference angle of 30°.
+    pub fn reference_angle(&self) -> Deg<N> {
+        let value = self.normalized().0;
+        let half: N = whole(180);
+        let value = if value > half { value - half } else { value };
+        if value > whole(90) {
+            Deg(half - value)
+        } else {
+            Deg(value)
         }
+    }
 
-        let actual = rotate([1., 0.], Deg(90.));
-        let expected = [0., 1.];
-        assert!(inexact_eq!(actual[0], expected[0]));
-        assert!(inexact_eq!(actual[1], expected[1]));
+    /// Convert anything that can become a `Deg`, naming the target unit
+    /// so generic code doesn't need a type annotation on `into()`.
+    #[inline]
+    pub fn from_angle<A: Into<Deg<N>>>(angle: A) -> Deg<N> {
+        angle.into()
     }
 
-    #[test]
-    fn test_generic() {
-        type Vector = [f64; 2];
+    /// Count the angles falling in each of `bins` equal sectors of the
+    /// circle, starting at 0° and going counterclockwise.
+    ///
+    /// Each bin includes its lower boundary and excludes its upper one,
+    /// so with four bins 90° is counted in the second bin. Angles are
+    /// normalized first, and NaN angles aren't counted.
+    pub fn histogram(angles: &[Deg<N>], bins: usize) -> Vec<usize> {
+        let mut counts = vec![0; bins];
+        if bins == 0 {
+            return counts;
+        }
 
-        /// Rotate vector counterclockwise by the given angle
-        fn rotate<T>(a: Vector, angle: T) -> Vector
-        where
-            T: Into<Deg<f64>>,
-        {
-            let r = angle.into().to_radians();
-            let x = a[0] * f64::cos(r) - a[1] * f64::sin(r);
-            let y = a[0] * f64::sin(r) + a[1] * f64::cos(r);
-            [x, y]
+        let width = whole::<N>(360) / float_count(bins);
+
+        for angle in angles {
+            if let Some(index) = (angle.normalized().0 / width).floor().to_usize() {
+                counts[index.min(bins - 1)] += 1;
+            }
         }
+        counts
+    }
 
-        let expected = [0., 1.];
+    /// Position on a 12-hour clock face for this bearing, in the range
+    /// [0, 12), where 0 is 12 o'clock straight up and hours increase
+    /// clockwise. A bearing of 90° is 3 o'clock.
+    #[inline]
+    pub fn to_clock_position(&self) -> N {
+        self.normalized().0 / whole(30)
+    }
 
-        {
-            let actual = rotate([1., 0.], 90.);
-            assert!(inexact_eq!(actual[0], expected[0]));
-            assert!(inexact_eq!(actual[1], expected[1]));
+    /// Bearing of a position on a 12-hour clock face, wrapped into
+    /// [0°, 360°), so both 0 and 12 o'clock are 0°.
+    #[inline]
+    pub fn from_clock_position(hours: N) -> Deg<N> {
+        Deg(hours * whole(30)).normalized()
+    }
+
+    /// Wrap the angle into the range [0°, 360°) exactly, however large
+    /// its magnitude, without relying on `%`.
+    ///
+    /// The magnitude is reduced by subtracting 360° scaled by powers of
+    /// two, largest first, and each subtraction is exact, so the remainder
+    /// is the true one of the stored value. Reducing by division instead,
+    /// like `x - 360 * floor(x / 360)`, rounds the quotient and is off by
+    /// whole degrees once angles reach around 1e17°, giving 288° there
+    /// instead of 280°. A negative angle then takes `360° - remainder`,
+    /// which can round, so a tiny negative angle comes out as 0° rather
+    /// than 360°.
+    ///
+    /// For `f32` and `f64` this matches [`normalized`](Deg::normalized),
+    /// whose `%` is also exact. It guarantees the same for float types
+    /// with a custom `%`, at the cost of a loop over the exponent range.
+    pub fn normalized_precise(&self) -> Deg<N> {
+        let full: N = whole(360);
+        let two: N = whole(2);
+        let mut rem = self.0.abs();
+        if !rem.is_finite() {
+            return Deg(N::nan());
         }
 
-        {
-            let actual = rotate([1., 0.], Deg(90.));
-            assert!(inexact_eq!(actual[0], expected[0]));
-            assert!(inexact_eq!(actual[1], expected[1]));
+        let mut step = full;
+        while step <= rem / two {
+            step = step * two;
+        }
+        while step >= full {
+            if rem >= step {
+                rem = rem - step;
+            }
+            step = step / two;
         }
 
-        {
-            let actual = rotate([1., 0.], Rad(std::f64::consts::PI / 2.));
-            assert!(inexact_eq!(actual[0], expected[0]));
-            assert!(inexact_eq!(actual[1], expected[1]));
+        if self.0 < N::zero() && rem != N::zero() {
+            let wrapped = full - rem;
+            Deg(if wrapped >= full { N::zero() } else { wrapped })
+        } else {
+            Deg(rem)
+        }
+    }
+
+    /// Apply a function to the inner value, keeping the unit.
+    #[inline]
+    pub fn map(&self, f: impl FnOnce(N) -> N) -> Deg<N> {
+        Deg(f(self.0))
+    }
+
+    /// Whether two angles are equivalent under a rotational symmetry of
+    /// the given period, like a square part with a period of 90°.
+    ///
+    /// Uses the same absolute epsilon as [`approx_eq`](Deg::approx_eq).
+    pub fn equivalent_mod(&self, other: Deg<N>, period: Deg<N>) -> bool {
+        let period = period.0.abs();
+        let rem = wrap(other.0 - self.0, period);
+        rem < N::epsilon() || period - rem < N::epsilon()
+    }
+
+    /// Whether two directions are parallel or anti-parallel, meaning the
+    /// shortest distance between them is within `eps` of 0° or 180°.
+    pub fn is_collinear_with(&self, other: Deg<N>, eps: Deg<N>) -> bool {
+        let distance = self.delta_to(other).0.abs();
+        distance <= eps.0 || whole::<N>(180) - distance <= eps.0
+    }
+
+    /// Rotate towards `target` along the shortest path, by at most
+    /// `max_delta`, like a turret with a limited turning speed.
+    ///
+    /// Returns `target` itself once it's within reach, otherwise this
+    /// angle moved by `max_delta`, without wrapping.
+    pub fn rotate_towards(&self, target: Deg<N>, max_delta: Deg<N>) -> Deg<N> {
+        let delta = self.delta_to(target).0;
+        let max_delta = max_delta.0.abs();
+        if delta.abs() <= max_delta {
+            target
+        } else {
+            Deg(self.0 + max_delta.copysign(delta))
+        }
+    }
+
+    /// Add two angles, returning `None` if the sum isn't finite.
+    #[inline]
+    pub fn checked_add(&self, other: Deg<N>) -> Option<Deg<N>> {
+        let sum = self.0 + other.0;
+        if sum.is_finite() {
+            Some(Deg(sum))
+        } else {
+            None
+        }
+    }
+
+    /// The evenly spaced bearings of a 4, 8 or 16 point compass rose,
+    /// clockwise from 0° north.
+    ///
+    /// Returns `None` for any other number of points.
+    pub fn compass_rose(points: u8) -> Option<impl Iterator<Item = Deg<N>>> {
+        if !matches!(points, 4 | 8 | 16) {
+            return None;
+        }
+        let step = whole::<N>(360) / whole(u32::from(points));
+        Some((0..u32::from(points)).map(move |i| Deg(step * whole(i))))
+    }
+
+    /// Degrees from an `i32`, or `None` if the float type can't represent it.
+    #[inline]
+    pub fn from_i32(value: i32) -> Option<Deg<N>> {
+        N::from_i32(value).map(Deg)
+    }
+
+    /// Degrees from an `i64`, or `None` if the float type can't represent it.
+    #[inline]
+    pub fn from_i64(value: i64) -> Option<Deg<N>> {
+        N::from_i64(value).map(Deg)
+    }
+
+    /// Degrees from an `isize`, or `None` if the float type can't represent it.
+    #[inline]
+    pub fn from_isize(value: isize) -> Option<Deg<N>> {
+        N::from_isize(value).map(Deg)
+    }
+
+    /// Degrees from an `u32`, or `None` if the float type can't represent it.
+    #[inline]
+    pub fn from_u32(value: u32) -> Option<Deg<N>> {
+        N::from_u32(value).map(Deg)
+    }
+
+    /// Degrees from an `u64`, or `None` if the float type can't represent it.
+    #[inline]
+    pub fn from_u64(value: u64) -> Option<Deg<N>> {
+        N::from_u64(value).map(Deg)
+    }
+
+    /// Degrees from an `usize`, or `None` if the float type can't represent it.
+    #[inline]
+    pub fn from_usize(value: usize) -> Option<Deg<N>> {
+        N::from_usize(value).map(Deg)
+    }
+
+    /// Split into whole degrees, truncated towards zero, and the
+    /// fractional remainder in (-1, 1). Both parts share the sign of the
+    /// angle, so -12.25° splits into -12 and -0.25.
+    ///
+    /// A whole part too large for `i64` is clamped to its limits, and a
+    /// NaN angle has a whole part of 0.
+    pub fn split_whole(&self) -> (i64, N) {
+        (saturating_int(self.0.trunc()), self.0.fract())
+    }
+
+    /// Clamp the raw value to `[lo, hi]` without any wraparound, unlike
+    /// [`wrap_then_clamp`](Deg::wrap_then_clamp), so 350° is clamped to
+    /// the upper limit rather than treated as -10°.
+    #[inline]
+    pub fn clamp_to_range(&self, lo: Deg<N>, hi: Deg<N>) -> Deg<N> {
+        Deg(self.0.max(lo.0).min(hi.0))
+    }
+
+    /// Snap this bearing, clockwise from north, to the nearest cardinal
+    /// direction.
+    ///
+    /// Each direction covers the 90° sector starting 45° before it, so a
+    /// bearing exactly between two directions snaps to the clockwise one:
+    /// 45° is east and 315° is north. A NaN bearing snaps to north.
+    pub fn to_cardinal(&self) -> Cardinal {
+        let quarter: N = whole(90);
+        let index = ((self.normalized().0 + whole(45)) / quarter).floor();
+        match index.to_u8().unwrap_or(0) % 4 {
+            1 => Cardinal::East,
+            2 => Cardinal::South,
+            3 => Cardinal::West,
+            _ => Cardinal::North,
         }
     }
+
+    /// Convert to radians, special-casing exact multiples of 90° so they
+    /// come out as the matching multiple of `FRAC_PI_2`.
+    ///
+    /// Together with [`Rad::to_degrees_exact_quadrant`] this makes quarter
+    /// turns round-trip exactly, where the plain conversions can drift by
+    /// a rounding error, like `Deg(450_f32)` coming back as 449.99997.
+    /// Any other angle falls back to [`to_radians`](Deg::to_radians).
+    pub fn to_radians_exact_quadrant(&self) -> N {
+        let quarters = self.0 / whole(90);
+        if quarters.fract() == N::zero() {
+            quarters * N::FRAC_PI_2()
+        } else {
+            self.to_radians()
+        }
+    }
+
+    /// Angle of the line segment from point `a` to point `b`, measured
+    /// counterclockwise from the x-axis. Coincident points give 0°.
+    #[inline]
+    pub fn from_points(a: [N; 2], b: [N; 2]) -> Deg<N> {
+        Rad::from_points(a, b).into()
+    }
+
+    /// Normalized copies of the angles, each wrapped into [0°, 360°).
+    pub fn normalize_all(angles: &[Deg<N>]) -> Vec<Deg<N>> {
+        angles.iter().map(Deg::normalized).collect()
+    }
+
+    /// Convert to radians wrapped into [0, 2π).
+    ///
+    /// The angle is wrapped in degrees first, where the reduction is exact.
+    #[inline]
+    pub fn to_radians_normalized(&self) -> N {
+        Rad(self.normalized().to_radians()).normalized().0
+    }
+
+    /// Heading rotated by `delta`, wrapped into [0°, 360°).
+    ///
+    /// The delta can be anything convertible to degrees, like a [`Rad`]
+    /// or a bare float in degrees.
+    #[inline]
+    pub fn rotated_by<A: Into<Deg<N>>>(&self, delta: A) -> Deg<N> {
+        Deg(self.0 + delta.into().0).normalized()
+    }
+
+    /// Whether the raw value lies in `[lo, hi]`, ignoring wraparound.
+    ///
+    /// Meant for monotonic angles that never wrap; use [`DegRange::contains`]
+    /// for an arc that may cross 0°.
+    #[inline]
+    pub fn is_between(&self, lo: Deg<N>, hi: Deg<N>) -> bool {
+        lo.0 <= self.0 && self.0 <= hi.0
+    }
+
+    /// Construct an angle, checking that `value` lies in `[lo, hi]`.
+    ///
+    /// Useful for validating a latitude or elevation from untrusted input.
+    /// NaN is never in range.
+    pub fn new_in_range(value: N, lo: N, hi: N) -> Result<Deg<N>, RangeError> {
+        if lo <= value && value <= hi {
+            Ok(Deg(value))
+        } else {
+            Err(RangeError(()))
+        }
+    }
+
+    /// Critically damped smoothing towards `target` along the shortest
+    /// path, the usual "smooth damp" used for camera follow.
+    ///
+    /// `velocity` is in degrees per unit of time and is carried between
+    /// calls. `smooth_time` is roughly how long reaching the target takes.
+    /// The result isn't wrapped, it stays next to this angle, and it never
+    /// overshoots the target.
+    pub fn smooth_damp(&self, target: Deg<N>, velocity: &mut N, smooth_time: N, dt: N) -> Deg<N> {
+        let two: N = whole(2);
+        let smooth_time = smooth_time.max(whole::<N>(10_000).recip());
+        let omega = two / smooth_time;
+
+        // Polynomial approximation of exp(-x).
+        let x = omega * dt;
+        let decay = (N::one()
+            + x
+            + whole::<N>(12) / whole(25) * x * x
+            + whole::<N>(47) / whole(200) * x * x * x)
+            .recip();
+
+        let goal = self.0 + self.delta_to(target).0;
+        let change = self.0 - goal;
+        let temp = (*velocity + omega * change) * dt;
+        *velocity = (*velocity - omega * temp) * decay;
+        let output = goal + (change + temp) * decay;
+
+        // Past the goal, in the direction of travel, or off it when
+        // starting there.
+        if (output - goal) * (goal - self.0) >= N::zero() {
+            *velocity = N::zero();
+            Deg(goal)
+        } else {
+            Deg(output)
+        }
+    }
+
+    /// The representation of this angle closest to `reference`, adding
+    /// or subtracting whole turns so it lies within ±180° of it.
+    ///
+    /// Feeding each new measurement the previous result as the reference
+    /// unwraps a signal that crosses the seam into a continuous one.
+    #[inline]
+    pub fn unwrap_near(&self, reference: Deg<N>) -> Deg<N> {
+        Deg(reference.0 + reference.delta_to(*self).0)
+    }
+
+    /// Reflect a direction of travel off a surface whose normal points
+    /// at `normal`, giving the outgoing direction in [0°, 360°).
+    ///
+    /// Both this angle and the result are directions of travel, so a ray
+    /// heading down at 315° onto a floor with normal 90° leaves at 45°.
+    /// The normal may point either way out of the surface.
+    #[inline]
+    pub fn reflect_off_normal(&self, normal: Deg<N>) -> Deg<N> {
+        Deg(whole::<N>(2) * normal.0 - self.0 + whole(180)).normalized()
+    }
+
+    /// Convert to NATO mils, where a full turn is 6400 mils.
+    ///
+    /// Other definitions exist, like the 6000 of some older artillery
+    /// and the 6283 of the milliradian, but NATO's is used here.
+    #[inline]
+    pub fn to_mils(&self) -> N {
+        self.0 * whole(160) / whole(9)
+    }
+
+    /// Angle from NATO mils, where a full turn is 6400 mils.
+    #[inline]
+    pub fn from_mils(mils: N) -> Deg<N> {
+        Deg(mils * whole(9) / whole(160))
+    }
+
+    /// Add `delta` to a running `total`, stopping at `max_total`, like a
+    /// winch whose spool can only take so many turns.
+    ///
+    /// Returns the part of `delta` that was actually applied, which is
+    /// zero once the limit is reached. Unwinding with a negative `delta`
+    /// is never limited.
+    pub fn accumulate_clamped(total: &mut Deg<N>, delta: Deg<N>, max_total: Deg<N>) -> Deg<N> {
+        let applied = if delta.0 > N::zero() {
+            delta.0.min((max_total.0 - total.0).max(N::zero()))
+        } else {
+            delta.0
+        };
+        total.0 = total.0 + applied;
+        Deg(applied)
+    }
+
+    /// Which way to turn to reach `target` by the shortest path, and the
+    /// unsigned distance along it.
+    ///
+    /// A target exactly opposite is reached by turning clockwise, matching
+    /// [`delta_to`](Deg::delta_to), which gives -180° rather than 180°.
+    pub fn shortest_direction_to(&self, target: Deg<N>) -> (RotationDir, Deg<N>) {
+        let delta = self.delta_to(target).0;
+        let dir = if delta > N::zero() {
+            RotationDir::CounterClockwise
+        } else if delta < N::zero() {
+            RotationDir::Clockwise
+        } else {
+            RotationDir::None
+        };
+        (dir, Deg(delta.abs()))
+    }
+
+    /// Convert to Q16.16 fixed point, with 16 fractional bits, rounding
+    /// to the nearest step of 1/65536°.
+    ///
+    /// The representable range is [-32768°, 32768°). Angles outside it
+    /// stick at the nearest end of the range, and NaN encodes as 0.
+    pub fn to_q16_16(&self) -> i32 {
+        saturating_int((self.0 * whole(65536)).round())
+    }
+
+    /// Angle from a Q16.16 fixed point value, with 16 fractional bits.
+    ///
+    /// The result is the nearest float to the fixed point value, which
+    /// for `f32` can differ from it beyond 256°. NaN is returned only if
+    /// the float type can't hold an `i32` at all.
+    pub fn from_q16_16(raw: i32) -> Deg<N> {
+        let value = N::from_i32(raw).unwrap_or_else(N::nan);
+        Deg(value / whole(65536))
+    }
+
+    /// Signs of the sine and cosine, as -1, 0 or 1, worked out from the
+    /// quadrant without any trigonometry.
+    ///
+    /// On the axes the matching component is 0, so 90° gives `(1, 0)`.
+    /// NaN gives `(0, 0)`.
+    pub fn trig_signs(&self) -> (i8, i8) {
+        if self.is_nan() {
+            return (0, 0);
+        }
+        let a = self.normalized().0;
+        let (quarter, half, three_quarters) = (whole(90), whole(180), whole(270));
+        let sin = if a == N::zero() || a == half {
+            0
+        } else if a < half {
+            1
+        } else {
+            -1
+        };
+        let cos = if a == quarter || a == three_quarters {
+            0
+        } else if a < quarter || a > three_quarters {
+            1
+        } else {
+            -1
+        };
+        (sin, cos)
+    }
+
+    /// Approximate equality of undirected lines, where angles half a turn
+    /// apart are the same, so a wall at 30° matches one at 210°.
+    #[inline]
+    pub fn equivalent_undirected(&self, other: Deg<N>, eps: Deg<N>) -> bool {
+        let d = self.delta_to(other).0.abs();
+        d.min(whole::<N>(180) - d) <= eps.0
+    }
+
+    /// Successive multiples of the golden angle, 180°(3 - √5) or about
+    /// 137.5°, each wrapped into [0°, 360°), starting from 0°.
+    ///
+    /// Placing the n-th point at the n-th angle, at a radius growing with
+    /// √n, gives the sunflower seed pattern of phyllotaxis.
+    ///
+    /// Each angle is worked out from its index, with the golden angle held
+    /// to twice the float's precision, so even `Deg<f32>` stays within
+    /// about 1e-4° for the first 2^24 indices, where `f32` stops counting.
+    pub fn golden_angle_sequence(count: usize) -> impl Iterator<Item = Deg<N>> {
+        // 180 * (3 - √5) as `hi + lo`, from the round-off of the square
+        // root and the product, both recovered exactly with `mul_add`.
+        let (five, third): (N, N) = (whole(5), whole(180));
+        let root = five.sqrt();
+        let root_lo = (-root).mul_add(root, five) / (root + root);
+        let product = third * root;
+        let product_lo = third.mul_add(root, -product);
+        let hi = whole::<N>(540) - product;
+        let lo = -(product_lo + third * root_lo);
+
+        (0..count).map(move |i| {
+            let i: N = float_count(i);
+            let turn = i * hi;
+            let turn_lo = i.mul_add(hi, -turn) + i * lo;
+            Deg(Deg(turn).normalized().0 + turn_lo).normalized()
+        })
+    }
+
+    /// The golden angle, 180°(3 - √5) or about 137.508°, dividing a turn
+    /// in the golden ratio.
+    #[inline]
+    pub fn golden_angle() -> Deg<N> {
+        Deg(whole::<N>(180) * (whole::<N>(3) - whole::<N>(5).sqrt()))
+    }
+
+    /// The magic angle, atan(√2) or about 54.7356°, between a cube's
+    /// space diagonal and its edges.
+    #[inline]
+    pub fn magic_angle() -> Deg<N> {
+        Rad(whole::<N>(2).sqrt().atan()).into()
+    }
+
+    /// The tetrahedral angle, acos(-1/3) or about 109.4712°, between the
+    /// bonds of a tetrahedral molecule like methane.
+    #[inline]
+    pub fn tetrahedral_angle() -> Deg<N> {
+        Rad((-whole::<N>(3).recip()).acos()).into()
+    }
+
+    /// Clamp to the counterclockwise arc from `start` to `end`, which may
+    /// cross 0°, snapping an angle outside it to the nearer end.
+    ///
+    /// An angle inside the arc is returned unchanged. One exactly
+    /// opposite the middle of the arc snaps to `start`.
+    pub fn clamp_arc(&self, start: Deg<N>, end: Deg<N>) -> Deg<N> {
+        if DegRange::new(start, end).contains(*self) {
+            *self
+        } else if self.ccw_distance_to(start).0 <= end.ccw_distance_to(*self).0 {
+            start
+        } else {
+            end
+        }
+    }
+
+    /// Direction halfway along the counterclockwise arc from `start` to
+    /// `end`, in [0°, 360°).
+    ///
+    /// Unlike the midpoint of the shortest path, this follows the wedge as
+    /// defined, so a reflex wedge from 0° to 270° bisects at 135°, not 315°.
+    #[inline]
+    pub fn wedge_bisector(start: Deg<N>, end: Deg<N>) -> Deg<N> {
+        let half = start.ccw_distance_to(end).0 / whole(2);
+        Deg(start.0 + half).normalized()
+    }
+
+    /// Split into signed whole turns and the remainder in [0°, 360°),
+    /// so 810° gives `(2, 90°)` and -90° gives `(-1, 270°)`.
+    ///
+    /// More turns than `i64` can count are reported as `i64::MAX` or
+    /// `i64::MIN`, and a NaN angle counts as 0 turns.
+    pub fn turns_and_remainder(&self) -> (i64, Deg<N>) {
+        let remainder = self.normalized();
+        // Derived from the remainder so the two parts always agree, even
+        // when a tiny negative angle wraps to 0°.
+        let turns = ((self.0 - remainder.0) / whole(360)).round();
+        (saturating_int(turns), remainder)
+    }
+}
+
+impl<N> Deg<N>
+where
+    N: Float + Into<f64>,
+{
+    /// Widen to `f64` before converting, so a `Deg<f32>` doesn't
+    /// pick up the round-off of an `f32` conversion.
+    #[inline]
+    pub fn to_radians_f64(&self) -> f64 {
+        let value: f64 = self.0.into();
+        value.to_radians()
+    }
+
+    /// Widen to `f64` before converting, giving mixed precision
+    /// `Deg<f32>` storage a direct path to `Rad<f64>`.
+    #[inline]
+    pub fn to_rad_f64(&self) -> Rad<f64> {
+        Rad(self.to_radians_f64())
+    }
+
+    /// Approximate equality within the `f64` machine epsilon, widening
+    /// both sides first, so the tolerance is the same whatever `N` is.
+    #[inline]
+    pub fn approx_eq_as_f64<T: Into<Self>>(&self, rhs: T) -> bool {
+        let lhs: f64 = self.0.into();
+        let rhs: f64 = rhs.into().0.into();
+        (lhs - rhs).abs() < f64::EPSILON
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<N> Deg<N>
+where
+    N: Float + FromPrimitive + FloatConst + Send + Sync,
+{
+    /// Convert a batch of angles to radians, spread across the rayon
+    /// thread pool.
+    pub fn par_to_radians(angles: &[Deg<N>]) -> Vec<N> {
+        use rayon::prelude::*;
+        angles.par_iter().map(Deg::to_radians).collect()
+    }
+}
+
+// Same bounds as the rayon version, so enabling the feature can't break
+// a generic caller.
+#[cfg(not(feature = "rayon"))]
+impl<N> Deg<N>
+where
+    N: Float + FromPrimitive + FloatConst + Send + Sync,
+{
+    /// Convert a batch of angles to radians. Enable the `rayon` feature
+    /// to spread the work across threads.
+    pub fn par_to_radians(angles: &[Deg<N>]) -> Vec<N> {
+        angles.iter().map(Deg::to_radians).collect()
+    }
+}
+
+impl Deg<f32> {
+    /// Sum the angles, accumulating in `f64` and narrowing only the
+    /// result, which avoids the error a long `f32` sum builds up.
+    pub fn sum_precise(angles: &[Deg<f32>]) -> Deg<f32> {
+        let total = angles.iter().map(|angle| f64::from(angle.0)).sum::<f64>();
+        Deg(total as f32)
+    }
+
+    /// Approximate equality within `ulps` units in the last place, a
+    /// tolerance that scales with the magnitude of the values.
+    ///
+    /// Positive and negative zero are equal, and NaN is never equal.
+    pub fn approx_eq_ulps<T: Into<Self>>(&self, rhs: T, ulps: u32) -> bool {
+        let (a, b) = (self.0, rhs.into().0);
+        if a.is_nan() || b.is_nan() {
+            return false;
+        }
+        ordered_bits_f32(a).abs_diff(ordered_bits_f32(b)) <= ulps
+    }
+
+    /// Raw bits of the inner float, for bit-exact serialization.
+    #[inline]
+    pub fn to_bits(&self) -> u32 {
+        self.0.to_bits()
+    }
+
+    /// Angle from the raw bits of a float, the inverse of
+    /// [`to_bits`](Deg::to_bits). NaN payloads are preserved.
+    #[inline]
+    pub fn from_bits(bits: u32) -> Deg<f32> {
+        Deg(f32::from_bits(bits))
+    }
+}
+
+impl Deg<f64> {
+    /// Narrow to `f32`, only if the value survives the round trip exactly.
+    ///
+    /// Otherwise the original value is returned as the error. NaN never
+    /// compares equal to itself, so it is always rejected.
+    pub fn to_f32_checked(&self) -> Result<Deg<f32>, f64> {
+        let narrowed = self.0 as f32;
+        if f64::from(narrowed) == self.0 {
+            Ok(Deg(narrowed))
+        } else {
+            Err(self.0)
+        }
+    }
+
+    /// Approximate equality within `ulps` units in the last place, a
+    /// tolerance that scales with the magnitude of the values.
+    ///
+    /// Positive and negative zero are equal, and NaN is never equal.
+    pub fn approx_eq_ulps<T: Into<Self>>(&self, rhs: T, ulps: u32) -> bool {
+        let (a, b) = (self.0, rhs.into().0);
+        if a.is_nan() || b.is_nan() {
+            return false;
+        }
+        ordered_bits_f64(a).abs_diff(ordered_bits_f64(b)) <= ulps.into()
+    }
+
+    /// Raw bits of the inner float, for bit-exact serialization.
+    #[inline]
+    pub fn to_bits(&self) -> u64 {
+        self.0.to_bits()
+    }
+
+    /// Angle from the raw bits of a float, the inverse of
+    /// [`to_bits`](Deg::to_bits). NaN payloads are preserved.
+    #[inline]
+    pub fn from_bits(bits: u64) -> Deg<f64> {
+        Deg(f64::from_bits(bits))
+    }
+
+    /// How far `nominal`, taken as the shortest decimal that parses to it,
+    /// like the literal `0.1`, is from the `f64` actually stored.
+    ///
+    /// Returns `decimal - stored`, so 0.5 gives 0 and 0.1 gives about
+    /// -5.55e-18. The difference is computed without rounding it away,
+    /// for decimal exponents within 10^±22. Beyond that, and for
+    /// non-finite values, the result is NaN.
+    pub fn representation_error(nominal: f64) -> f64 {
+        const POW10: [f64; 23] = [
+            1e0, 1e1, 1e2, 1e3, 1e4, 1e5, 1e6, 1e7, 1e8, 1e9, 1e10, 1e11, 1e12, 1e13, 1e14, 1e15,
+            1e16, 1e17, 1e18, 1e19, 1e20, 1e21, 1e22,
+        ];
+        if !nominal.is_finite() {
+            return f64::NAN;
+        }
+        let x = nominal.abs();
+
+        // Shortest round trip decimal, as integer digits and a power of ten.
+        let s = format!("{:e}", x);
+        let (mantissa, exp) = s.split_once('e').unwrap_or((&s, "0"));
+        let (int, frac) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+        let digits: u64 = format!("{}{}", int, frac).parse().unwrap_or(0);
+        let exp = exp.parse::<i32>().unwrap_or(0) - frac.len() as i32;
+        let pow = match POW10.get(exp.unsigned_abs() as usize) {
+            Some(&pow) => pow,
+            None => return f64::NAN,
+        };
+
+        // Split the digits so both halves are exact floats.
+        let (hi, lo) = ((digits >> 11 << 11) as f64, (digits & 0x7ff) as f64);
+        let error = if exp < 0 {
+            // (digits - x * pow) / pow, with the product kept exactly
+            // as the sum of `p` and `p_lo`.
+            let p = x * pow;
+            let p_lo = x.mul_add(pow, -p);
+            ((hi - p) + lo - p_lo) / pow
+        } else {
+            lo.mul_add(pow, hi.mul_add(pow, -x))
+        };
+        if nominal < 0.0 {
+            -error
+        } else {
+            error
+        }
+    }
+}
+
+impl<N> From<Deg<N>> for Rad<N>
+where
+    N: Float + FromPrimitive + FloatConst,
+{
+    fn from(deg: Deg<N>) -> Rad<N> {
+        Rad(deg.to_radians())
+    }
+}
+
+impl<N> From<&Deg<N>> for Rad<N>
+where
+    N: Float + FromPrimitive + FloatConst,
+{
+    #[inline]
+    fn from(deg: &Deg<N>) -> Rad<N> {
+        Rad(deg.to_radians())
+    }
+}
+
+/// Convert float to degrees.
+impl<N: Float> From<N> for Deg<N> {
+    #[inline]
+    fn from(value: N) -> Deg<N> {
+        Deg(value)
+    }
+}
+
+impl<N> fmt::Display for Deg<N>
+where
+    N: Float + fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({})", self.0)
+    }
+}
+
+/// Parse an angle from a number with an optional unit suffix,
+/// like `"45"`, `"45deg"` or `"45°"`.
+impl<N> FromStr for Deg<N>
+where
+    N: Float + FromStr,
+{
+    type Err = ParseAngleError;
+
+    fn from_str(s: &str) -> Result<Deg<N>, ParseAngleError> {
+        let value = strip_unit(s.trim(), &["deg", "°"]);
+        value.parse().map(Deg).map_err(|_| ParseAngleError(()))
+    }
+}
+
+impl<N: Float> From<Deg<N>> for [N; 1] {
+    #[inline]
+    fn from(angle: Deg<N>) -> [N; 1] {
+        [angle.0]
+    }
+}
+
+impl<N: Float> From<[N; 1]> for Deg<N> {
+    #[inline]
+    fn from(array: [N; 1]) -> Deg<N> {
+        Deg(array[0])
+    }
+}
+
+/// View the angle as a one element array, for passing a pointer to a
+/// single float across FFI without copying.
+impl<N: Float> AsRef<[N; 1]> for Deg<N> {
+    #[inline]
+    fn as_ref(&self) -> &[N; 1] {
+        std::array::from_ref(&self.0)
+    }
+}
+
+impl<N: Float> AsMut<[N; 1]> for Deg<N> {
+    #[inline]
+    fn as_mut(&mut self) -> &mut [N; 1] {
+        std::array::from_mut(&mut self.0)
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+#[repr(transparent)]
+pub struct Rad<N: Float>(pub N);
+
+impl<N> Rad<N>
+where
+    N: Float + FromPrimitive + FloatConst,
+{
+    #[inline]
+    pub fn value(&self) -> N {
+        self.0
+    }
+
+    #[inline]
+    pub fn to_degrees(&self) -> N {
+        let d: N = whole(180);
+        let pi = N::PI();
+        self.0 * (d / pi)
+    }
+
+    /// Approximate equality within the machine epsilon of `N`.
+    ///
+    /// The tolerance depends on the float type, so `f32` angles compare
+    /// far more loosely than `f64` ones. Use `approx_eq_as_f64` for the
+    /// same tolerance regardless of `N`.
+    #[inline]
+    pub fn approx_eq<T: Into<Self>>(&self, rhs: T) -> bool {
+        (self.0 - rhs.into().0).abs() < Float::epsilon()
+    }
+
+    /// Round to the nearest whole radian, away from zero on a tie.
+    #[inline]
+    pub fn round(&self) -> Rad<N> {
+        Rad(self.0.round())
+    }
+
+    /// Largest whole radian less than or equal to the angle.
+    #[inline]
+    pub fn floor(&self) -> Rad<N> {
+        Rad(self.0.floor())
+    }
+
+    /// Smallest whole radian greater than or equal to the angle.
+    #[inline]
+    pub fn ceil(&self) -> Rad<N> {
+        Rad(self.0.ceil())
+    }
+
+    /// Whole radian part of the angle, truncated towards zero.
+    #[inline]
+    pub fn trunc(&self) -> Rad<N> {
+        Rad(self.0.trunc())
+    }
+
+    /// Wrap the angle into the range [0, 2π).
+    ///
+    /// Zero is always returned as positive zero, like [`Deg::normalized`].
+    #[inline]
+    pub fn normalized(&self) -> Rad<N> {
+        Rad(wrap(self.0, Rad::tau().0))
+    }
+
+    /// The angle as a fraction of a full circle, in the range [0, 1).
+    ///
+    /// The angle is normalized first, so `π/2` and `5π/2` are both `0.25`.
+    #[inline]
+    pub fn as_fraction(&self) -> N {
+        self.normalized().0 / Rad::tau().0
+    }
+
+    /// Angle covering the given fraction of a full circle.
+    #[inline]
+    pub fn from_fraction(fraction: N) -> Rad<N> {
+        Rad(fraction * Rad::tau().0)
+    }
+
+    /// Returns `true` if the angle is NaN.
+    #[inline]
+    pub fn is_nan(&self) -> bool {
+        self.0.is_nan()
+    }
+
+    /// Returns `true` if the angle is positive or negative infinity.
+    #[inline]
+    pub fn is_infinite(&self) -> bool {
+        self.0.is_infinite()
+    }
+
+    /// Returns `true` if the angle is neither infinite nor NaN.
+    #[inline]
+    pub fn is_finite(&self) -> bool {
+        self.0.is_finite()
+    }
+
+    /// Angle of a slope with the given rise over run, measured from the
+    /// horizontal. A vertical slope with zero run is ±π/2, following the
+    /// sign of the rise.
+    #[inline]
+    pub fn from_slope(rise: N, run: N) -> Rad<N> {
+        Rad(rise.atan2(run))
+    }
+
+    /// Pair this angle with a normalized rotation axis, ready to build a
+    /// quaternion or rotation matrix from.
+    ///
+    /// An axis with zero length can't be normalized and is returned
+    /// as-is, so the caller can decide how to handle it.
+    pub fn axis_angle(&self, axis: [N; 3]) -> ([N; 3], N) {
+        let len = (axis[0] * axis[0] + axis[1] * axis[1] + axis[2] * axis[2]).sqrt();
+        if len == N::zero() {
+            return (axis, self.0);
+        }
+        ([axis[0] / len, axis[1] / len, axis[2] / len], self.0)
+    }
+
+    /// Quaternion `[x, y, z, w]` for a rotation of this angle about the Z axis.
+    #[inline]
+    pub fn to_quat_z(&self) -> [N; 4] {
+        let (sin, cos) = (self.0 / whole(2)).sin_cos();
+        [N::zero(), N::zero(), sin, cos]
+    }
+
+    /// Fused `self * mul + add`, rounded once using the float's `mul_add`.
+    #[inline]
+    pub fn mul_add(&self, mul: N, add: Rad<N>) -> Rad<N> {
+        Rad(self.0.mul_add(mul, add.0))
+    }
+
+    /// Half a turn, π radians.
+    #[inline]
+    pub fn pi() -> Rad<N> {
+        Rad(N::PI())
+    }
+
+    /// A full turn, 2π radians.
+    #[inline]
+    pub fn tau() -> Rad<N> {
+        Rad(N::TAU())
+    }
+
+    /// Convert anything that can become a `Rad`, naming the target unit
+    /// so generic code doesn't need a type annotation on `into()`.
+    #[inline]
+    pub fn from_angle<A: Into<Rad<N>>>(angle: A) -> Rad<N> {
+        angle.into()
+    }
+
+    /// Area of a circular sector with this angle, `0.5 * r² * θ`.
+    ///
+    /// The angle is taken as the sweep from 0 to it counterclockwise, in
+    /// (0, 2π], so negative angles give their positive counterpart and a
+    /// full turn is the whole circle. Only zero gives zero.
+    #[inline]
+    pub fn sector_area(&self, radius: N) -> N {
+        radius * radius * self.sweep() / whole(2)
+    }
+
+    /// Length of a circular arc with this angle, `r * θ`, with the angle
+    /// taken as a sweep the same way as [`sector_area`](Rad::sector_area).
+    #[inline]
+    pub fn arc_length(&self, radius: N) -> N {
+        radius * self.sweep()
+    }
+
+    /// Counterclockwise sweep in (0, 2π], or zero for a zero angle.
+    fn sweep(&self) -> N {
+        let sweep = self.normalized().0;
+        if sweep == N::zero() && self.0 != N::zero() {
+            N::TAU()
+        } else {
+            sweep
+        }
+    }
+
+    /// Apply a function to the inner value, keeping the unit.
+    #[inline]
+    pub fn map(&self, f: impl FnOnce(N) -> N) -> Rad<N> {
+        Rad(f(self.0))
+    }
+
+    /// Oscillator phase in [0, 1) as an angle in [0, 2π). Phases outside
+    /// the range wrap around, so 1.25 is the same as 0.25.
+    #[inline]
+    pub fn from_phase01(phase: N) -> Rad<N> {
+        Rad::from_fraction(phase).normalized()
+    }
+
+    /// The angle as an oscillator phase, wrapped into [0, 1).
+    #[inline]
+    pub fn to_phase01(&self) -> N {
+        self.as_fraction()
+    }
+
+    /// Convert to degrees, special-casing exact multiples of `FRAC_PI_2`
+    /// so they come out as exact multiples of 90°.
+    ///
+    /// This is the inverse of [`Deg::to_radians_exact_quadrant`]. Any
+    /// other angle falls back to [`to_degrees`](Rad::to_degrees).
+    pub fn to_degrees_exact_quadrant(&self) -> N {
+        let quarters = (self.0 / N::FRAC_PI_2()).round();
+        if quarters * N::FRAC_PI_2() == self.0 {
+            quarters * whole(90)
+        } else {
+            self.to_degrees()
+        }
+    }
+
+    /// Angle of the line segment from point `a` to point `b`, measured
+    /// counterclockwise from the x-axis. Coincident points give 0.
+    pub fn from_points(a: [N; 2], b: [N; 2]) -> Rad<N> {
+        let (dx, dy) = (b[0] - a[0], b[1] - a[1]);
+        if dx == N::zero() && dy == N::zero() {
+            Rad(N::zero())
+        } else {
+            Rad(dy.atan2(dx))
+        }
+    }
+
+    /// Convert to degrees wrapped into [0°, 360°).
+    #[inline]
+    pub fn to_degrees_normalized(&self) -> N {
+        Deg(self.normalized().to_degrees()).normalized().0
+    }
+
+    /// Whether the raw value lies in `[lo, hi]`, ignoring wraparound.
+    #[inline]
+    pub fn is_between(&self, lo: Rad<N>, hi: Rad<N>) -> bool {
+        lo.0 <= self.0 && self.0 <= hi.0
+    }
+
+    /// Convert to NATO mils, where a full turn is 6400 mils.
+    #[inline]
+    pub fn to_mils(&self) -> N {
+        self.0 * whole(3200) / N::PI()
+    }
+
+    /// Angle from NATO mils, where a full turn is 6400 mils.
+    #[inline]
+    pub fn from_mils(mils: N) -> Rad<N> {
+        Rad(mils * N::PI() / whole(3200))
+    }
+
+    /// Angle between two vectors from their dot product and lengths,
+    /// `acos(dot / (len_a * len_b))`.
+    ///
+    /// The cosine is clamped into [-1, 1] first, so round-off in
+    /// near-parallel vectors can't produce NaN. A zero-length vector
+    /// still gives NaN.
+    pub fn from_dot(dot: N, len_a: N, len_b: N) -> Rad<N> {
+        let cos = dot / (len_a * len_b);
+        Rad(cos.clamp(-N::one(), N::one()).acos())
+    }
+
+    /// Angle of a 2D rotation matrix, row major with `m[1][0]` as the
+    /// sine, in the range (-π, π].
+    ///
+    /// Only the first column is read, so a matrix that has drifted from
+    /// orthonormal or picked up a uniform scale still gives its angle.
+    pub fn from_rotation_matrix_2d(m: [[N; 2]; 2]) -> Rad<N> {
+        let angle = m[1][0].atan2(m[0][0]);
+        Rad(if angle == -N::PI() { N::PI() } else { angle })
+    }
+}
+
+impl<N> Rad<N>
+where
+    N: Float + Into<f64>,
+{
+    /// Widen to `f64` before converting, so a `Rad<f32>` doesn't
+    /// pick up the round-off of an `f32` conversion.
+    #[inline]
+    pub fn to_degrees_f64(&self) -> f64 {
+        let value: f64 = self.0.into();
+        value.to_degrees()
+    }
+
+    /// Widen to `f64` before converting, giving mixed precision
+    /// `Rad<f32>` storage a direct path to `Deg<f64>`.
+    #[inline]
+    pub fn to_deg_f64(&self) -> Deg<f64> {
+        Deg(self.to_degrees_f64())
+    }
+
+    /// Approximate equality within the `f64` machine epsilon, widening
+    /// both sides first, so the tolerance is the same whatever `N` is.
+    #[inline]
+    pub fn approx_eq_as_f64<T: Into<Self>>(&self, rhs: T) -> bool {
+        let lhs: f64 = self.0.into();
+        let rhs: f64 = rhs.into().0.into();
+        (lhs - rhs).abs() < f64::EPSILON
+    }
+}
+
+impl<N> From<Rad<N>> for Deg<N>
+where
+    N: Float + FromPrimitive + FloatConst,
+{
+    #[inline]
+    fn from(rad: Rad<N>) -> Deg<N> {
+        Deg(rad.to_degrees())
+    }
+}
+
+impl<N> From<&Rad<N>> for Deg<N>
+where
+    N: Float + FromPrimitive + FloatConst,
+{
+    #[inline]
+    fn from(rad: &Rad<N>) -> Deg<N> {
+        Deg(rad.to_degrees())
+    }
+}
+
+/// Convert float to radians.
+impl<N: Float> From<N> for Rad<N> {
+    #[inline]
+    fn from(value: N) -> Rad<N> {
+        Rad(value)
+    }
+}
+
+impl<N> fmt::Display for Rad<N>
+where
+    N: Float + fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({})", self.0)
+    }
+}
+
+/// Parse an angle from a number with an optional unit suffix,
+/// like `"1.5"` or `"1.5rad"`.
+impl<N> FromStr for Rad<N>
+where
+    N: Float + FromStr,
+{
+    type Err = ParseAngleError;
+
+    fn from_str(s: &str) -> Result<Rad<N>, ParseAngleError> {
+        let value = strip_unit(s.trim(), &["rad"]);
+        value.parse().map(Rad).map_err(|_| ParseAngleError(()))
+    }
+}
+
+impl<N: Float> From<Rad<N>> for [N; 1] {
+    #[inline]
+    fn from(angle: Rad<N>) -> [N; 1] {
+        [angle.0]
+    }
+}
+
+impl<N: Float> From<[N; 1]> for Rad<N> {
+    #[inline]
+    fn from(array: [N; 1]) -> Rad<N> {
+        Rad(array[0])
+    }
+}
+
+/// View the angle as a one element array, for passing a pointer to a
+/// single float across FFI without copying.
+impl<N: Float> AsRef<[N; 1]> for Rad<N> {
+    #[inline]
+    fn as_ref(&self) -> &[N; 1] {
+        std::array::from_ref(&self.0)
+    }
+}
+
+impl<N: Float> AsMut<[N; 1]> for Rad<N> {
+    #[inline]
+    fn as_mut(&mut self) -> &mut [N; 1] {
+        std::array::from_mut(&mut self.0)
+    }
+}
+
+/// Rotate a 2D vector counterclockwise by the given angle.
+///
+/// ```
+/// use angle::{rotate_vec2, Deg};
+///
+/// let rotated = rotate_vec2([1., 0.], Deg(90_f64));
+/// assert!(rotated[0].abs() < 1e-12);
+/// assert!((rotated[1] - 1.).abs() < 1e-12);
+/// ```
+pub fn rotate_vec2<N, A>(v: [N; 2], angle: A) -> [N; 2]
+where
+    N: Float + FromPrimitive + FloatConst,
+    A: Into<Rad<N>>,
+{
+    let (sin, cos) = angle.into().value().sin_cos();
+    [v[0] * cos - v[1] * sin, v[0] * sin + v[1] * cos]
+}
+
+/// Convert polar coordinates to a cartesian point `[x, y]`.
+pub fn polar_to_cartesian<N, A>(r: N, angle: A) -> [N; 2]
+where
+    N: Float + FromPrimitive + FloatConst,
+    A: Into<Rad<N>>,
+{
+    let (sin, cos) = angle.into().value().sin_cos();
+    [r * cos, r * sin]
+}
+
+/// Convert a cartesian point `[x, y]` to polar coordinates, with the
+/// angle in the range (-π, π].
+pub fn cartesian_to_polar<N>(point: [N; 2]) -> (N, Rad<N>)
+where
+    N: Float + FromPrimitive + FloatConst,
+{
+    (point[0].hypot(point[1]), Rad(point[1].atan2(point[0])))
+}
+
+/// Unit direction `[x, y, z]` for an azimuth, counterclockwise around the
+/// z-axis from the x-axis, and an elevation above the xy-plane.
+pub fn spherical_to_cartesian<N, A, B>(azimuth: A, elevation: B) -> [N; 3]
+where
+    N: Float + FromPrimitive + FloatConst,
+    A: Into<Rad<N>>,
+    B: Into<Rad<N>>,
+{
+    let (sin_az, cos_az) = azimuth.into().value().sin_cos();
+    let (sin_el, cos_el) = elevation.into().value().sin_cos();
+    [cos_el * cos_az, cos_el * sin_az, sin_el]
+}
+
+/// Azimuth in (-π, π] and elevation in [-π/2, π/2] of a direction
+/// `[x, y, z]`, which doesn't need to be normalized. Directions straight
+/// up or down have an azimuth of 0.
+pub fn cartesian_to_spherical<N>(v: [N; 3]) -> (Rad<N>, Rad<N>)
+where
+    N: Float + FromPrimitive + FloatConst,
+{
+    let azimuth = Rad::from_points([N::zero(), N::zero()], [v[0], v[1]]);
+    (azimuth, Rad(v[2].atan2(v[0].hypot(v[1]))))
+}
+
+/// `count` evenly spaced points on the unit circle, starting at `[1, 0]`
+/// and going counterclockwise.
+pub fn unit_circle_points<N>(count: usize) -> impl Iterator<Item = [N; 2]>
+where
+    N: Float + FromPrimitive + FloatConst,
+{
+    let total: N = float_count(count);
+    (0..count).map(move |i| {
+        let (sin, cos) = Rad::from_fraction(float_count::<N>(i) / total)
+            .value()
+            .sin_cos();
+        [cos, sin]
+    })
+}
+
+/// One of the four cardinal directions of a compass.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Cardinal {
+    North,
+    East,
+    South,
+    West,
+}
+
+/// Which way to turn to reach an angle.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RotationDir {
+    Clockwise,
+    CounterClockwise,
+    /// Already at the target.
+    None,
+}
+
+/// Format a value with at most `max_decimals` places, trimming trailing zeros.
+fn format_compact<N: fmt::Display>(value: N, max_decimals: usize, unit: &str) -> String {
+    let mut s = format!("{:.*}", max_decimals, value);
+    if s.contains('.') {
+        s.truncate(s.trim_end_matches('0').trim_end_matches('.').len());
+    }
+    if s == "-0" {
+        s.remove(0);
+    }
+    s.push_str(unit);
+    s
+}
+
+impl<N> Deg<N>
+where
+    N: Float + fmt::Display,
+{
+    /// Compact display string like `45.1°`, rounded to at most
+    /// `max_decimals` places with trailing zeros trimmed.
+    pub fn format_compact(&self, max_decimals: usize) -> String {
+        format_compact(self.0, max_decimals, "°")
+    }
+}
+
+impl<N> Rad<N>
+where
+    N: Float + fmt::Display,
+{
+    /// Compact display string like `1.57rad`, rounded to at most
+    /// `max_decimals` places with trailing zeros trimmed.
+    pub fn format_compact(&self, max_decimals: usize) -> String {
+        format_compact(self.0, max_decimals, "rad")
+    }
+}
+
+/// Error returned when parsing an angle from a string fails.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseAngleError(());
+
+impl fmt::Display for ParseAngleError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("invalid angle literal")
+    }
+}
+
+impl Error for ParseAngleError {}
+
+/// Error returned when an angle lies outside its allowed range.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RangeError(());
+
+impl fmt::Display for RangeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("angle out of range")
+    }
+}
+
+impl Error for RangeError {}
+
+/// Remove the first matching unit suffix, and any space before it.
+fn strip_unit<'a>(s: &'a str, units: &[&str]) -> &'a str {
+    units
+        .iter()
+        .find_map(|unit| s.strip_suffix(unit))
+        .map_or(s, str::trim_end)
+}
+
+/// Approximate equality comparison for floating point numbers.
+#[macro_export]
+macro_rules! inexact_eq {
+    ($lhs:expr, $rhs:expr) => {
+        (f64::from($lhs) - f64::from($rhs)).abs() < f64::EPSILON
+    };
+}
+
+/// Require at compile time that an expression is a `Rad`, evaluating to it.
+///
+/// Documents call sites that must receive radians, in generic code where
+/// a bare `.value()` would lose track of the unit.
+///
+/// ```
+/// use angle::{assert_radians, Rad};
+///
+/// let angle = assert_radians!(Rad(1.5_f32));
+/// assert_eq!(angle.value(), 1.5);
+/// ```
+///
+/// ```compile_fail
+/// use angle::{assert_radians, Deg};
+///
+/// let angle = assert_radians!(Deg(90_f32));
+/// ```
+#[macro_export]
+macro_rules! assert_radians {
+    ($angle:expr) => {{
+        let angle: $crate::Rad<_> = $angle;
+        angle
+    }};
+}
+
+/// Require at compile time that an expression is a `Deg`, evaluating to it.
+///
+/// Documents call sites that must receive degrees, in generic code where
+/// a bare `.value()` would lose track of the unit.
+///
+/// ```
+/// use angle::{assert_degrees, Deg};
+///
+/// let angle = assert_degrees!(Deg(90_f64));
+/// assert_eq!(angle.value(), 90.);
+/// ```
+///
+/// ```compile_fail
+/// use angle::{assert_degrees, Rad};
+///
+/// let angle = assert_degrees!(Rad(1.5_f64));
+/// ```
+#[macro_export]
+macro_rules! assert_degrees {
+    ($angle:expr) => {{
+        let angle: $crate::Deg<_> = $angle;
+        angle
+    }};
+}
+
+/// Angle widened to `f64` radians, used by [`assert_angle_eq!`].
+#[doc(hidden)]
+pub trait RadiansF64 {
+    fn radians_f64(&self) -> f64;
+}
+
+impl<N: Float + Into<f64>> RadiansF64 for Deg<N> {
+    #[inline]
+    fn radians_f64(&self) -> f64 {
+        self.to_radians_f64()
+    }
+}
+
+impl<N: Float + Into<f64>> RadiansF64 for Rad<N> {
+    #[inline]
+    fn radians_f64(&self) -> f64 {
+        self.0.into()
+    }
+}
+
+/// Assert that two angles, in degrees or radians, are equal within
+/// `eps` radians.
+///
+/// Both sides are converted to `f64` radians before comparing, so a
+/// `Deg` can be checked against a `Rad`. On failure both values are
+/// shown in both units.
+///
+/// ```
+/// use angle::{assert_angle_eq, Deg, Rad};
+///
+/// assert_angle_eq!(Deg(90_f32), Rad(std::f64::consts::FRAC_PI_2), 1e-6);
+/// ```
+#[macro_export]
+macro_rules! assert_angle_eq {
+    ($lhs:expr, $rhs:expr, $eps:expr) => {{
+        let lhs = $crate::RadiansF64::radians_f64(&$lhs);
+        let rhs = $crate::RadiansF64::radians_f64(&$rhs);
+        let eps: f64 = $eps;
+        let within = (lhs - rhs).abs() <= eps;
+        if !within {
+            panic!(
+                "assertion failed: `{} ≈ {}` within {} rad\n  left: {}° ({} rad)\n right: {}° ({} rad)",
+                stringify!($lhs),
+                stringify!($rhs),
+                eps,
+                lhs.to_degrees(),
+                lhs,
+                rhs.to_degrees(),
+                rhs,
+            );
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_degrees() {
+        let deg_45 = Deg(45.);
+        let rad_45 = ::std::f32::consts::PI / 4.;
+        assert!(inexact_eq!(rad_45, deg_45.to_radians()));
+        assert!(Rad(rad_45).approx_eq(deg_45));
+    }
+
+    #[test]
+    fn test_radians() {
+        let pi = ::std::f32::consts::PI;
+        let deg_45 = 45.;
+        let rad_45 = Rad(pi / 4.);
+        assert!(inexact_eq!(deg_45, rad_45.to_degrees()));
+        assert!(Deg(deg_45).approx_eq(rad_45));
+    }
+
+    /// Using a float directly as degrees or rads.
+    #[test]
+    fn test_info() {
+        fn sum_f32<T: Into<Deg<f32>>>(lhs: T, rhs: T) -> Deg<f32> {
+            Deg(lhs.into().value() + rhs.into().value())
+        }
+        assert_eq!(sum_f32(Deg(45.), Deg(75.)), Deg(120.));
+        assert_eq!(sum_f32(45., 75.), Deg(120.));
+    }
+
+    #[test]
+    fn test_non_generic() {
+        type Vector = [f64; 2];
+
+        /// Rotate vector counterclockwise by the given angle
+        fn rotate(a: Vector, angle: Deg<f64>) -> Vector {
+            rotate_vec2(a, angle)
+        }
+
+        let actual = rotate([1., 0.], Deg(90.));
+        let expected = [0., 1.];
+        assert!(inexact_eq!(actual[0], expected[0]));
+        assert!(inexact_eq!(actual[1], expected[1]));
+    }
+
+    #[test]
+    fn test_generic() {
+        type Vector = [f64; 2];
+
+        /// Rotate vector counterclockwise by the given angle
+        fn rotate<T>(a: Vector, angle: T) -> Vector
+        where
+            T: Into<Deg<f64>>,
+        {
+            rotate_vec2(a, angle.into())
+        }
+
+        let expected = [0., 1.];
+
+        {
+            let actual = rotate([1., 0.], 90.);
+            assert!(inexact_eq!(actual[0], expected[0]));
+            assert!(inexact_eq!(actual[1], expected[1]));
+        }
+
+        {
+            let actual = rotate([1., 0.], Deg(90.));
+            assert!(inexact_eq!(actual[0], expected[0]));
+            assert!(inexact_eq!(actual[1], expected[1]));
+        }
+
+        {
+            let actual = rotate([1., 0.], Rad(std::f64::consts::PI / 2.));
+            assert!(inexact_eq!(actual[0], expected[0]));
+            assert!(inexact_eq!(actual[1], expected[1]));
+        }
+    }
+
+    #[test]
+    fn test_rounding() {
+        assert_eq!(Deg(45.7).round(), Deg(46.));
+        assert_eq!(Deg(45.7).floor(), Deg(45.));
+        assert_eq!(Deg(45.2).ceil(), Deg(46.));
+        assert_eq!(Deg(-45.7).trunc(), Deg(-45.));
+
+        assert_eq!(Rad(1.5).round(), Rad(2.));
+        assert_eq!(Rad(1.5).floor(), Rad(1.));
+        assert_eq!(Rad(1.5).ceil(), Rad(2.));
+        assert_eq!(Rad(-1.5).trunc(), Rad(-1.));
+    }
+
+    #[test]
+    fn test_widening_conversion() {
+        let deg = Deg(123.456_f32);
+        let expected = f64::from(deg.value()) * std::f64::consts::PI / 180.;
+        let widened = (deg.to_radians_f64() - expected).abs();
+        let narrow = (f64::from(deg.to_radians()) - expected).abs();
+        assert!(widened < narrow);
+
+        let rad = Rad(2.5_f32);
+        let expected = f64::from(rad.value()) * 180. / std::f64::consts::PI;
+        let widened = (rad.to_degrees_f64() - expected).abs();
+        let narrow = (f64::from(rad.to_degrees()) - expected).abs();
+        assert!(widened < narrow);
+    }
+
+    #[test]
+    fn test_circular_mean() {
+        let mean = Deg::circular_mean(&[Deg(350.), Deg(10.)]).unwrap();
+        assert!(mean.value().abs() < 1e-9);
+
+        let mean = Deg::circular_mean(&[Deg(45.), Deg(90.), Deg(135.)]).unwrap();
+        assert!((mean.value() - 90.).abs() < 1e-9);
+
+        assert_eq!(Deg::<f64>::circular_mean(&[]), None);
+        assert_eq!(Deg::circular_mean(&[Deg(0.), Deg(180.)]), None);
+        assert_eq!(Deg::circular_mean(&[Deg(0.), Deg(120.), Deg(240.)]), None);
+    }
+
+    #[test]
+    fn test_normalized() {
+        assert_eq!(Deg(370.).normalized(), Deg(10.));
+        assert_eq!(Deg(-90.).normalized(), Deg(270.));
+        assert_eq!(Deg(360.).normalized(), Deg(0.));
+        assert_eq!(Deg(-1e-20_f64).normalized(), Deg(0.));
+    }
+
+    #[test]
+    fn test_cmp_around() {
+        let reference = Deg(300.);
+        let mut angles = vec![Deg(10.), Deg(290.), Deg(350.), Deg(300.), Deg(120.)];
+        angles.sort_by(|a, b| a.cmp_around(b, reference));
+        assert_eq!(
+            angles,
+            vec![Deg(300.), Deg(350.), Deg(10.), Deg(120.), Deg(290.)]
+        );
+    }
+
+    #[test]
+    fn test_from_rate() {
+        assert_eq!(Deg::from_rate(90., 2.5), Deg(225.));
+        assert_eq!(Deg::from_rate(90., 5.), Deg(450.));
+        assert_eq!(Deg::from_rate(90., 5.).normalized(), Deg(90.));
+    }
+
+    #[test]
+    fn test_whole() {
+        for n in [0, 1, 2, 90, 180, 360, 6400, 65536, 1_000_000] {
+            assert_eq!(whole::<f32>(n), n as f32);
+            assert_eq!(whole::<f64>(n), n as f64);
+        }
+        assert_eq!(whole::<f64>(u32::MAX), u32::MAX as f64);
+    }
+
+    #[test]
+    fn test_screen() {
+        assert_eq!(Deg(90.).to_screen(), Deg(270.));
+        assert_eq!(Deg(0.).to_screen(), Deg(0.));
+        assert_eq!(Deg(-45.).to_screen(), Deg(45.));
+        assert_eq!(Deg::from_screen(Deg(270.)), Deg(90.));
+        assert_eq!(Deg::from_screen(Deg(30.).to_screen()), Deg(30.));
+    }
+
+    #[test]
+    fn test_approx_eq_rel() {
+        assert!(!Deg(100000.).approx_eq(100000.0001));
+        assert!(Deg(100000.).approx_eq_rel(100000.0001, 1e-6));
+        assert!(!Deg(100000.).approx_eq_rel(100001., 1e-6));
+
+        assert!(Deg(1e-10).approx_eq_rel(1.0000001e-10, 1e-6));
+        assert!(!Deg(1e-10).approx_eq_rel(2e-10, 1e-6));
+        assert!(Deg(0.).approx_eq_rel(0., 1e-6));
+    }
+
+    #[test]
+    fn test_rotate_vec2() {
+        let eps = 1e-12;
+        let close =
+            |a: [f64; 2], b: [f64; 2]| (a[0] - b[0]).abs() < eps && (a[1] - b[1]).abs() < eps;
+
+        assert!(close(rotate_vec2([1., 0.], Deg(0.)), [1., 0.]));
+        assert!(close(rotate_vec2([1., 0.], Deg(180.)), [-1., 0.]));
+        assert!(close(rotate_vec2([1., 0.], Deg(-90.)), [0., -1.]));
+        assert!(close(
+            rotate_vec2([0., 2.], Rad(std::f64::consts::PI / 2.)),
+            [-2., 0.]
+        ));
+        assert!(close(
+            rotate_vec2([1., 1.], std::f64::consts::PI),
+            [-1., -1.]
+        ));
+
+        let half = std::f64::consts::FRAC_1_SQRT_2;
+        assert!(close(rotate_vec2([1., 0.], Deg(45.)), [half, half]));
+    }
+
+    #[test]
+    fn test_fraction() {
+        use std::f64::consts::PI;
+
+        assert_eq!(Deg(90.).as_fraction(), 0.25);
+        assert_eq!(Deg(180.).as_fraction(), 0.5);
+        assert_eq!(Deg(360.).as_fraction(), 0.);
+        assert_eq!(Deg(-90.).as_fraction(), 0.75);
+        assert_eq!(Deg::from_fraction(0.25), Deg(90.));
+        assert_eq!(Deg::from_fraction(0.5), Deg(180.));
+        assert_eq!(Deg::from_fraction(1.), Deg(360.));
+
+        assert!(inexact_eq!(Rad(PI / 2.).as_fraction(), 0.25));
+        assert!(inexact_eq!(Rad(PI).as_fraction(), 0.5));
+        assert_eq!(Rad(PI * 2.).as_fraction(), 0.);
+        assert!(Rad::from_fraction(0.25).approx_eq(PI / 2.));
+        assert!(Rad::from_fraction(0.5).approx_eq(PI));
+        assert!(Rad::from_fraction(1.).approx_eq(PI * 2.));
+    }
+
+    #[test]
+    fn test_delta_to() {
+        assert_eq!(Deg(10.).delta_to(Deg(30.)), Deg(20.));
+        assert_eq!(Deg(30.).delta_to(Deg(10.)), Deg(-20.));
+        assert_eq!(Deg(350.).delta_to(Deg(10.)), Deg(20.));
+        assert_eq!(Deg(10.).delta_to(Deg(350.)), Deg(-20.));
+        assert_eq!(Deg(0.).delta_to(Deg(180.)), Deg(-180.));
+    }
+
+    #[test]
+    fn test_approx_eq_wrapped() {
+        assert!(!Deg(0.).approx_eq(359.9999));
+        assert!(Deg(0.).approx_eq_wrapped(359.9999, 1e-3));
+        assert!(Deg(359.9999).approx_eq_wrapped(Deg(720.), 1e-3));
+        assert!(!Deg(0.).approx_eq_wrapped(10., 1e-3));
+        assert!(!Deg(0.).approx_eq_wrapped(180., 1e-3));
+    }
+
+    #[test]
+    fn test_float_predicates() {
+        let nan = Deg(f64::NAN);
+        assert!(nan.is_nan());
+        assert!(!nan.is_infinite());
+        assert!(!nan.is_finite());
+
+        let inf = Rad(f32::NEG_INFINITY);
+        assert!(!inf.is_nan());
+        assert!(inf.is_infinite());
+        assert!(!inf.is_finite());
+
+        assert!(Deg(45.).is_finite());
+        assert!(Rad(1.).is_finite());
+        assert!(!Rad(f64::NAN).is_finite());
+        assert!(Deg(f32::INFINITY).is_infinite());
+    }
+
+    #[test]
+    fn test_from_slope() {
+        use std::f64::consts::{FRAC_PI_2, FRAC_PI_4};
+
+        assert!(Deg::from_slope(1., 1.).approx_eq(45.));
+        assert_eq!(Deg::from_slope(0., 1.), Deg(0.));
+        assert_eq!(Deg::from_slope(1., 0.), Deg(90.));
+        assert_eq!(Deg::from_slope(-3., 0.), Deg(-90.));
+
+        assert_eq!(Rad::from_slope(2., 2.), Rad(FRAC_PI_4));
+        assert_eq!(Rad::from_slope(0., 5.), Rad(0.));
+        assert_eq!(Rad::from_slope(5., 0.), Rad(FRAC_PI_2));
+        assert_eq!(Rad::from_slope(-5., 0.), Rad(-FRAC_PI_2));
+    }
+
+    #[test]
+    fn test_compass_point() {
+        assert_eq!(Deg(0.).to_compass_point(), "N");
+        assert_eq!(Deg(45.).to_compass_point(), "NE");
+        assert_eq!(Deg(200.).to_compass_point(), "SSW");
+        assert_eq!(Deg(355.).to_compass_point(), "N");
+        assert_eq!(Deg(-90.).to_compass_point(), "W");
+        assert_eq!(Deg(11.25).to_compass_point(), "NNE");
+
+        assert_eq!(Deg::from_compass_point("N"), Some(Deg(0.)));
+        assert_eq!(Deg::from_compass_point("ssw"), Some(Deg(202.5)));
+        assert_eq!(Deg::from_compass_point("NNW"), Some(Deg(337.5)));
+        assert_eq!(Deg::<f64>::from_compass_point("North"), None);
+    }
+
+    #[test]
+    fn test_normalized_signed() {
+        assert_eq!(Deg(190.).normalized_signed(), Deg(-170.));
+        assert_eq!(Deg(-190.).normalized_signed(), Deg(170.));
+        assert_eq!(Deg(180.).normalized_signed(), Deg(-180.));
+        assert_eq!(Deg(720.).normalized_signed(), Deg(0.));
+    }
+
+    #[test]
+    fn test_wrap_then_clamp() {
+        let (lo, hi) = (Deg(-30.), Deg(30.));
+        assert_eq!(Deg(350.).wrap_then_clamp(lo, hi), Deg(-10.));
+        assert_eq!(Deg(10.).wrap_then_clamp(lo, hi), Deg(10.));
+        assert_eq!(Deg(300.).wrap_then_clamp(lo, hi), Deg(-30.));
+        assert_eq!(Deg(-320.).wrap_then_clamp(lo, hi), Deg(30.));
+
+        let (lo, hi) = (Deg(-90.), Deg(90.));
+        assert_eq!(Deg(200.).wrap_then_clamp(lo, hi), Deg(-90.));
+        assert_eq!(Deg(100.).wrap_then_clamp(lo, hi), Deg(90.));
+    }
+
+    #[test]
+    fn test_axis_angle() {
+        let angle: Rad<f64> = Deg(90.).into();
+        let (axis, theta) = angle.axis_angle([0., 0., 1.]);
+        assert_eq!(axis, [0., 0., 1.]);
+        assert_eq!(theta, std::f64::consts::FRAC_PI_2);
+
+        let (axis, _) = angle.axis_angle([0., 0., -4.]);
+        assert_eq!(axis, [0., 0., -1.]);
+
+        let (axis, _) = angle.axis_angle([0., 0., 0.]);
+        assert_eq!(axis, [0., 0., 0.]);
+    }
+
+    #[test]
+    fn test_to_quat_z() {
+        let half = std::f64::consts::FRAC_1_SQRT_2;
+
+        let q = Deg(90.).to_quat_z();
+        assert_eq!([q[0], q[1]], [0., 0.]);
+        assert!(inexact_eq!(q[2], half));
+        assert!(inexact_eq!(q[3], half));
+
+        let q = Rad(std::f64::consts::PI).to_quat_z();
+        assert_eq!([q[0], q[1], q[2]], [0., 0., 1.]);
+        assert!(q[3].abs() < 1e-15);
+
+        assert_eq!(Deg(0.).to_quat_z(), [0., 0., 0., 1.]);
+    }
+
+    #[test]
+    fn test_total_winding() {
+        let path: Vec<_> = (0..7)
+            .map(|i| Deg(f64::from(i) * 90.).normalized())
+            .collect();
+        assert_eq!(Deg::total_winding(&path), Deg(540.));
+
+        let back: Vec<_> = path.iter().rev().copied().collect();
+        assert_eq!(Deg::total_winding(&back), Deg(-540.));
+
+        assert_eq!(
+            Deg::total_winding(&[Deg(350.), Deg(10.), Deg(340.)]),
+            Deg(-10.)
+        );
+        assert_eq!(Deg::<f64>::total_winding(&[]), Deg(0.));
+    }
+
+    #[test]
+    fn test_percent_grade() {
+        assert_eq!(Deg::from_percent_grade(0.), Deg(0.));
+        assert!(Deg::from_percent_grade(100.).approx_eq(45.));
+        assert!((Deg::from_percent_grade(300.).value() - 71.565_051_177).abs() < 1e-9);
+
+        assert_eq!(Deg(0.).to_percent_grade(), 0.);
+        assert!((Deg(45.).to_percent_grade() - 100.).abs() < 1e-12);
+        assert!((Deg::from_percent_grade(12.5).to_percent_grade() - 12.5).abs() < 1e-12);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_postcard() {
+        let bytes = postcard::to_allocvec(&Deg(45_f32)).unwrap();
+        assert_eq!(bytes.len(), std::mem::size_of::<f32>());
+        assert_eq!(bytes, 45_f32.to_le_bytes());
+        assert_eq!(postcard::from_bytes::<Deg<f32>>(&bytes).unwrap(), Deg(45.));
+
+        let bytes = postcard::to_allocvec(&Rad(1.5_f64)).unwrap();
+        assert_eq!(bytes.len(), std::mem::size_of::<f64>());
+        assert_eq!(postcard::from_bytes::<Rad<f64>>(&bytes).unwrap(), Rad(1.5));
+    }
+
+    #[test]
+    fn test_sum_precise() {
+        let angles = vec![Deg(0.1_f32); 1_000_000];
+        let exact = angles.iter().map(|a| f64::from(a.value())).sum::<f64>();
+        let naive = angles.iter().fold(0_f32, |total, a| total + a.value());
+        let precise = Deg::sum_precise(&angles).value();
+
+        let naive_err = (f64::from(naive) - exact).abs();
+        let precise_err = (f64::from(precise) - exact).abs();
+        assert!(precise_err < 0.01);
+        assert!(precise_err * 100. < naive_err);
+    }
+
+    #[test]
+    fn test_nearest_of() {
+        let targets = [Deg(0.), Deg(90.), Deg(180.), Deg(270.)];
+        assert_eq!(Deg(350.).nearest_of(&targets), Some(Deg(0.)));
+        assert_eq!(Deg(100.).nearest_of(&targets), Some(Deg(90.)));
+
+        let targets = [Deg(20.), Deg(300.)];
+        assert_eq!(Deg(350.).nearest_of(&targets), Some(Deg(20.)));
+        assert_eq!(Deg(-40.).nearest_of(&targets), Some(Deg(300.)));
+
+        assert_eq!(Deg(10.).nearest_of(&[]), None);
+    }
+
+    #[test]
+    fn test_polar() {
+        let close =
+            |a: [f64; 2], b: [f64; 2]| (a[0] - b[0]).abs() < 1e-12 && (a[1] - b[1]).abs() < 1e-12;
+
+        assert!(close(polar_to_cartesian(2., Deg(90.)), [0., 2.]));
+        assert!(close(
+            polar_to_cartesian(1., Rad(std::f64::consts::PI)),
+            [-1., 0.]
+        ));
+
+        let (r, angle) = cartesian_to_polar([0., -3.]);
+        assert_eq!(r, 3.);
+        assert!(Deg(-90.).approx_eq(angle));
+
+        for point in [[1., 0.], [3., 4.], [-2., 5.], [-1., -1.]] {
+            let (r, angle) = cartesian_to_polar(point);
+            assert!(close(polar_to_cartesian(r, angle), point));
+        }
+    }
+
+    #[test]
+    fn test_to_f32_checked() {
+        assert_eq!(Deg(45.5_f64).to_f32_checked(), Ok(Deg(45.5_f32)));
+        assert_eq!(Deg(-270_f64).to_f32_checked(), Ok(Deg(-270_f32)));
+        assert_eq!(Deg(0.1_f64).to_f32_checked(), Err(0.1));
+        assert_eq!(Deg(1e300_f64).to_f32_checked(), Err(1e300));
+        assert!(Deg(f64::NAN).to_f32_checked().is_err());
+    }
+
+    #[test]
+    fn test_interpolate_steps() {
+        assert_eq!(
+            Deg(0.).interpolate_steps(Deg(90.), 4),
+            vec![Deg(0.), Deg(30.), Deg(60.), Deg(90.)]
+        );
+        assert_eq!(
+            Deg(340.).interpolate_steps(Deg(20.), 5),
+            vec![Deg(340.), Deg(350.), Deg(0.), Deg(10.), Deg(20.)]
+        );
+        assert_eq!(Deg(10.).interpolate_steps(Deg(20.), 1), vec![Deg(10.)]);
+        assert_eq!(Deg(10.).interpolate_steps(Deg(20.), 0), vec![]);
+    }
+
+    #[test]
+    fn test_assert_unit() {
+        fn heading<N>(angle: Deg<N>) -> N
+        where
+            N: Float + FromPrimitive + FloatConst,
+        {
+            assert_degrees!(angle).value()
+        }
+        assert_eq!(heading(Deg(90_f32)), 90.);
+        assert_eq!(
+            assert_radians!(Rad::from(Deg(180.))).value(),
+            std::f64::consts::PI
+        );
+    }
+
+    #[test]
+    fn test_mul_add() {
+        let (base, coefficient, t) = (Rad(0.3), 1.7, Rad(0.25));
+        let fused = base.mul_add(coefficient, t);
+        assert!((fused.value() - (base.value() * coefficient + t.value())).abs() < 1e-15);
+
+        assert_eq!(Deg(30.).mul_add(2., Deg(15.)), Deg(75.));
+        assert_eq!(Rad(0.5).mul_add(-2., Rad(1.)), Rad(0.));
+    }
+
+    #[test]
+    fn test_unit_circle_points() {
+        let points: Vec<[f64; 2]> = unit_circle_points(8).collect();
+        assert_eq!(points.len(), 8);
+        assert_eq!(points[0], [1., 0.]);
+        assert!(points[2][0].abs() < 1e-15 && inexact_eq!(points[2][1], 1.));
+        for point in &points {
+            assert!((point[0].hypot(point[1]) - 1.).abs() < 1e-15);
+        }
+
+        assert_eq!(unit_circle_points::<f32>(0).count(), 0);
+    }
+
+    #[test]
+    fn test_mixed_precision() {
+        let deg = Deg(123.456_f32);
+        let expected = f64::from(deg.value()) * std::f64::consts::PI / 180.;
+        let naive = Rad(f64::from(Rad::from(deg).value()));
+        assert!((deg.to_rad_f64().value() - expected).abs() < (naive.value() - expected).abs());
+
+        let rad = Rad(2.5_f32);
+        let expected = f64::from(rad.value()) * 180. / std::f64::consts::PI;
+        let naive = Deg(f64::from(Deg::from(rad).value()));
+        assert!((rad.to_deg_f64().value() - expected).abs() < (naive.value() - expected).abs());
+
+        assert!(Deg(90_f32)
+            .to_rad_f64()
+            .approx_eq(std::f64::consts::FRAC_PI_2));
+    }
+
+    #[test]
+    fn test_array() {
+        let array: [f32; 1] = Deg(45.).into();
+        assert_eq!(array, [45.]);
+        assert_eq!(Deg::from(array), Deg(45.));
+        assert_eq!(Rad::from([1.5_f64]), Rad(1.5));
+
+        let mut deg = Deg(90_f64);
+        let ptr = deg.as_ref().as_ptr();
+        assert_eq!(ptr, &deg.0 as *const f64);
+        assert_eq!(ptr, &deg as *const Deg<f64> as *const f64);
+
+        deg.as_mut()[0] = 180.;
+        assert_eq!(deg, Deg(180.));
+    }
+
+    #[test]
+    fn test_polygon_angles() {
+        assert_eq!(Deg::regular_interior(3), Deg(60.));
+        assert_eq!(Deg::regular_interior(4), Deg(90.));
+        assert_eq!(Deg::regular_interior(6), Deg(120.));
+
+        assert_eq!(Deg::regular_exterior(3), Deg(120.));
+        assert_eq!(Deg::regular_exterior(4), Deg(90.));
+        assert_eq!(Deg::regular_exterior(6), Deg(60.));
+
+        assert_eq!(Deg::exterior_of(Deg(60.)), Deg(120.));
+        assert_eq!(
+            Deg::exterior_of(Deg::<f64>::regular_interior(6)),
+            Deg::regular_exterior(6)
+        );
+
+        // Huge side counts divide by the correctly rounded f32.
+        let sides = 1_189_442_682;
+        assert_eq!(
+            Deg::<f32>::regular_exterior(sides),
+            Deg(360. / sides as f32)
+        );
+        let n = sides as f32;
+        assert_eq!(
+            Deg::<f32>::regular_interior(sides),
+            Deg((n - 2.) * 180. / n)
+        );
+    }
+
+    #[test]
+    fn test_directed_distance() {
+        assert_eq!(Deg(350.).ccw_distance_to(Deg(10.)), Deg(20.));
+        assert_eq!(Deg(10.).ccw_distance_to(Deg(350.)), Deg(340.));
+        assert_eq!(Deg(10.).cw_distance_to(Deg(350.)), Deg(20.));
+        assert_eq!(Deg(350.).cw_distance_to(Deg(10.)), Deg(340.));
+        assert_eq!(Deg(90.).ccw_distance_to(Deg(90.)), Deg(0.));
+        assert_eq!(Deg(90.).cw_distance_to(Deg(450.)), Deg(0.));
+    }
+
+    #[test]
+    fn test_rad_consts() {
+        assert!(Rad::pi().approx_eq(Deg(180.)));
+        assert!(Rad::tau().approx_eq(Deg(360_f32)));
+        assert_eq!(Rad::<f64>::tau(), Rad(std::f64::consts::TAU));
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!("45".parse(), Ok(Deg(45_f64)));
+        assert_eq!("45deg".parse(), Ok(Deg(45_f64)));
+        assert_eq!(" -12.5 ° ".parse(), Ok(Deg(-12.5_f32)));
+        assert_eq!("1.5rad".parse(), Ok(Rad(1.5_f64)));
+        assert_eq!("1.5".parse(), Ok(Rad(1.5_f64)));
+        assert_eq!("45rad".parse::<Deg<f64>>(), Err(ParseAngleError(())));
+        assert!("deg".parse::<Deg<f64>>().is_err());
+    }
+
+    #[test]
+    fn test_reference_angle() {
+        assert_eq!(Deg(30.).reference_angle(), Deg(30.));
+        assert_eq!(Deg(150.).reference_angle(), Deg(30.));
+        assert_eq!(Deg(210.).reference_angle(), Deg(30.));
+        assert_eq!(Deg(330.).reference_angle(), Deg(30.));
+        assert_eq!(Deg(-60.).reference_angle(), Deg(60.));
+        assert_eq!(Deg(90.).reference_angle(), Deg(90.));
+        assert_eq!(Deg(180.).reference_angle(), Deg(0.));
+        assert_eq!(Deg(270.).reference_angle(), Deg(90.));
+    }
+
+    #[test]
+    fn test_from_angle() {
+        use std::f64::consts::PI;
+
+        assert!(Rad::from_angle(Deg(180.)).approx_eq(PI));
+        assert_eq!(Rad::from_angle(Rad(PI)), Rad(PI));
+        assert_eq!(Rad::from_angle(PI), Rad(PI));
+
+        assert!(Deg::from_angle(Rad(PI)).approx_eq(180.));
+        assert_eq!(Deg::from_angle(Deg(90.)), Deg(90.));
+        assert_eq!(Deg::from_angle(90.), Deg(90.));
+
+        assert_eq!(
+            Deg::from_angle(Rad::from_angle(Deg(90_f32))).round(),
+            Deg(90.)
+        );
+    }
+
+    #[test]
+    fn test_normalized_positive_zero() {
+        let zero = 0_f64.to_bits();
+        assert_eq!(Deg(-0.0_f64).normalized().value().to_bits(), zero);
+        assert_eq!(Deg(-360.0_f64).normalized().value().to_bits(), zero);
+        assert_eq!(Deg(-0.0_f64).normalized_signed().value().to_bits(), zero);
+        assert_eq!(Deg(-0.0_f64).to_screen().value().to_bits(), zero);
+        assert_eq!(Deg(-0.0_f64).delta_to(Deg(0.)).value().to_bits(), zero);
+        assert_eq!(Rad(-0.0_f64).normalized().value().to_bits(), zero);
+        assert_eq!(
+            Deg(-0.0_f32).normalized().value().to_bits(),
+            0_f32.to_bits()
+        );
+    }
+
+    #[test]
+    fn test_sector() {
+        use std::f64::consts::PI;
+
+        let quarter = Rad(PI / 2.);
+        assert!(inexact_eq!(quarter.sector_area(2.), PI));
+        assert!(inexact_eq!(quarter.arc_length(2.), PI));
+        assert!(inexact_eq!(Rad(-PI / 2.).sector_area(2.), 3. * PI));
+        assert!(inexact_eq!(Rad(-PI / 2.).arc_length(2.), 3. * PI));
+        assert_eq!(Rad(0.).sector_area(5.), 0.);
+        assert_eq!(Rad(0.).arc_length(5.), 0.);
+
+        // A whole pie, not an empty slice.
+        assert!(inexact_eq!(Rad(2. * PI).sector_area(1.), PI));
+        assert!(inexact_eq!(Rad(2. * PI).arc_length(1.), 2. * PI));
+        assert!(inexact_eq!(Rad(-4. * PI).sector_area(1.), PI));
+    }
+
+    #[test]
+    fn test_from_ref() {
+        let degrees = [Deg(90.), Deg(180.)];
+        let rad: Rad<f64> = (&degrees[0]).into();
+        assert!(rad.approx_eq(Deg(90.)));
+
+        let radians: Vec<Rad<f64>> = degrees.iter().map(Rad::from).collect();
+        assert!(radians[1].approx_eq(std::f64::consts::PI));
+
+        let back: Vec<Deg<f64>> = radians.iter().map(Deg::from).collect();
+        assert!(back[0].approx_eq(90.));
+        assert!(back[1].approx_eq(180.));
+    }
+
+    #[test]
+    fn test_histogram() {
+        let angles = [
+            Deg(10.),
+            Deg(20.),
+            Deg(355.),
+            Deg(-30.),
+            Deg(45.),
+            Deg(100.),
+        ];
+        assert_eq!(Deg::histogram(&angles, 4), vec![3, 1, 0, 2]);
+
+        let boundaries = [Deg(0.), Deg(90.), Deg(180.), Deg(270.), Deg(360.)];
+        assert_eq!(Deg::histogram(&boundaries, 4), vec![2, 1, 1, 1]);
+
+        assert_eq!(Deg::histogram(&[Deg(f64::NAN)], 2), vec![0, 0]);
+        assert!(Deg::histogram(&angles, 0).is_empty());
+    }
+
+    #[test]
+    fn test_clock_position() {
+        assert_eq!(Deg(0.).to_clock_position(), 0.);
+        assert_eq!(Deg(90.).to_clock_position(), 3.);
+        assert_eq!(Deg(180.).to_clock_position(), 6.);
+        assert_eq!(Deg(270.).to_clock_position(), 9.);
+        assert_eq!(Deg(360.).to_clock_position(), 0.);
+        assert_eq!(Deg(-15.).to_clock_position(), 11.5);
+
+        assert_eq!(Deg::from_clock_position(12.), Deg(0.));
+        assert_eq!(Deg::from_clock_position(3.), Deg(90.));
+        assert_eq!(Deg::from_clock_position(6.), Deg(180.));
+        assert_eq!(Deg::from_clock_position(9.), Deg(270.));
+    }
+
+    #[test]
+    fn test_normalized_precise() {
+        let naive = |x: f64| x - 360. * (x / 360.).floor();
+
+        // Division based reduction diverges from the exact one.
+        let huge = Deg(1e17);
+        assert_eq!(huge.normalized_precise(), Deg(280.));
+        assert_eq!(naive(huge.value()), 288.);
+        assert_eq!(huge.normalized_precise(), huge.normalized());
+
+        let huger = Deg(-3e30);
+        assert_eq!(huger.normalized_precise(), huger.normalized());
+        assert_ne!(huger.normalized_precise().value(), naive(huger.value()));
+
+        // 360 - 1e-20 rounds up to a full turn.
+        assert_eq!(Deg(-1e-20_f64).normalized_precise(), Deg(0.));
+        assert_eq!(Deg(-1e-6_f32).normalized_precise(), Deg(0.));
+        assert_eq!(
+            Deg(-1e-20_f64).normalized_precise(),
+            Deg(-1e-20_f64).normalized()
+        );
+
+        assert_eq!(Deg(725.5).normalized_precise(), Deg(5.5));
+        assert_eq!(Deg(-90.).normalized_precise(), Deg(270.));
+        assert_eq!(
+            Deg(-720_f64).normalized_precise().value().to_bits(),
+            0_f64.to_bits()
+        );
+        assert_eq!(
+            Deg(1e30_f32).normalized_precise(),
+            Deg(1e30_f32).normalized()
+        );
+        assert!(Deg(f64::INFINITY).normalized_precise().is_nan());
+    }
+
+    #[test]
+    fn test_map() {
+        assert_eq!(Deg(100.).map(|v| v.min(90.)), Deg(90.));
+        assert_eq!(Rad(2.).map(|v| v * v), Rad(4.));
+        assert_eq!(Deg(-30_f32).map(f32::abs), Deg(30.));
+    }
+
+    #[test]
+    fn test_equivalent_mod() {
+        let square = Deg(90.);
+        assert!(Deg(30.).equivalent_mod(Deg(120.), square));
+        assert!(Deg(30.).equivalent_mod(Deg(-60.), square));
+        assert!(Deg(10.).equivalent_mod(Deg(370.), square));
+        assert!(!Deg(30.).equivalent_mod(Deg(75.), square));
+
+        let triangle = Deg(120.);
+        assert!(Deg(0.).equivalent_mod(Deg(240.), triangle));
+        assert!(Deg(15.).equivalent_mod(Deg(135.), triangle));
+        assert!(!Deg(0.).equivalent_mod(Deg(90.), triangle));
+    }
+
+    #[test]
+    fn test_format_compact() {
+        assert_eq!(Deg(45.1).format_compact(3), "45.1°");
+        assert_eq!(Deg(45.).format_compact(3), "45°");
+        assert_eq!(Deg(45.1234).format_compact(2), "45.12°");
+        assert_eq!(Deg(45.996).format_compact(2), "46°");
+        assert_eq!(Deg(-0.001).format_compact(2), "0°");
+        assert_eq!(Deg(12.5).format_compact(0), "12°");
+        assert_eq!(
+            Rad(std::f64::consts::FRAC_PI_2).format_compact(2),
+            "1.57rad"
+        );
+        assert_eq!(Rad(2.500_f32).format_compact(4), "2.5rad");
+    }
+
+    #[test]
+    fn test_is_collinear_with() {
+        let eps = Deg(1e-9);
+        assert!(Deg(30.).is_collinear_with(Deg(30.), eps));
+        assert!(Deg(30.).is_collinear_with(Deg(390.), eps));
+        assert!(Deg(30.).is_collinear_with(Deg(210.), eps));
+        assert!(Deg(350.).is_collinear_with(Deg(170.), eps));
+        assert!(!Deg(30.).is_collinear_with(Deg(120.), eps));
+        assert!(Deg(0.).is_collinear_with(Deg(179.5), Deg(1.)));
+    }
+
+    #[test]
+    fn test_rotate_towards() {
+        assert_eq!(Deg(10.).rotate_towards(Deg(20.), Deg(15.)), Deg(20.));
+        assert_eq!(Deg(10.).rotate_towards(Deg(40.), Deg(15.)), Deg(25.));
+        assert_eq!(Deg(10.).rotate_towards(Deg(300.), Deg(15.)), Deg(-5.));
+        assert_eq!(Deg(350.).rotate_towards(Deg(20.), Deg(15.)), Deg(365.));
+        assert_eq!(Deg(355.).rotate_towards(Deg(5.), Deg(15.)), Deg(5.));
+    }
+
+    #[test]
+    fn test_phase01() {
+        use std::f64::consts::{PI, TAU};
+
+        assert_eq!(Rad::from_phase01(0.), Rad(0.));
+        assert!(Rad::from_phase01(0.5).approx_eq(PI));
+        assert!(Rad::from_phase01(0.999).approx_eq(0.999 * TAU));
+        assert!(Rad::from_phase01(1.25).approx_eq(PI / 2.));
+        assert_eq!(Rad::from_phase01(1.), Rad(0.));
+
+        assert_eq!(Rad(0.).to_phase01(), 0.);
+        assert!(inexact_eq!(Rad(PI).to_phase01(), 0.5));
+        assert!(inexact_eq!(Rad(-PI / 2.).to_phase01(), 0.75));
+    }
+
+    #[test]
+    fn test_checked_add() {
+        assert_eq!(Deg(30.).checked_add(Deg(45.)), Some(Deg(75.)));
+        assert_eq!(Deg(350.).checked_add(Deg(20.)), Some(Deg(370.)));
+        assert_eq!(Deg(f64::INFINITY).checked_add(Deg(1.)), None);
+        assert_eq!(Deg(1.).checked_add(Deg(f64::NAN)), None);
+        assert_eq!(Deg(f32::MAX).checked_add(Deg(f32::MAX)), None);
+    }
+
+    #[test]
+    fn test_compass_rose() {
+        let rose: Vec<Deg<f64>> = Deg::compass_rose(8).unwrap().collect();
+        let expected: Vec<_> = (0..8).map(|i| Deg(f64::from(i) * 45.)).collect();
+        assert_eq!(rose, expected);
+
+        assert_eq!(Deg::<f64>::compass_rose(4).unwrap().last(), Some(Deg(270.)));
+        assert_eq!(
+            Deg::<f32>::compass_rose(16).unwrap().nth(1),
+            Some(Deg(22.5))
+        );
+        assert!(Deg::<f64>::compass_rose(6).is_none());
+        assert!(Deg::<f64>::compass_rose(0).is_none());
+    }
+
+    #[test]
+    fn test_from_integers() {
+        assert_eq!(Deg::from_i32(-90), Some(Deg(-90_f32)));
+        assert_eq!(Deg::from_i64(360), Some(Deg(360_f64)));
+        assert_eq!(Deg::from_isize(-1), Some(Deg(-1_f64)));
+        assert_eq!(Deg::from_u32(45), Some(Deg(45_f32)));
+        assert_eq!(Deg::from_u64(u64::MAX), Some(Deg(u64::MAX as f64)));
+        assert_eq!(Deg::from_usize(720), Some(Deg(720_f64)));
+
+        // Past 2^24 an f32 can't hold every integer and rounds to the nearest.
+        assert_eq!(Deg::from_i64(16_777_217), Some(Deg(16_777_216_f32)));
+        assert_eq!(Deg::from_i64(16_777_217), Some(Deg(16_777_217_f64)));
+    }
+
+    #[test]
+    fn test_split_whole() {
+        assert_eq!(Deg(45.75).split_whole(), (45, 0.75));
+        assert_eq!(Deg(-12.25).split_whole(), (-12, -0.25));
+        assert_eq!(Deg(90.).split_whole(), (90, 0.));
+        assert_eq!(Deg(1e300).split_whole().0, i64::MAX);
+        assert_eq!(Deg(f64::NAN).split_whole().0, 0);
+    }
+
+    #[test]
+    fn test_approx_eq_ulps() {
+        let a = 90_f64;
+        let b = f64::from_bits(a.to_bits() + 3);
+        assert!(Deg(a).approx_eq_ulps(b, 3));
+        assert!(!Deg(a).approx_eq_ulps(b, 2));
+
+        let big = 1e20_f32;
+        let next = f32::from_bits(big.to_bits() + 1);
+        assert!(Deg(big).approx_eq_ulps(next, 1));
+        assert!(!Deg(big).approx_eq_ulps(next, 0));
+
+        let tiny = f64::from_bits(1);
+        assert!(Deg(tiny).approx_eq_ulps(-tiny, 2));
+        assert!(Deg(0_f64).approx_eq_ulps(-0_f64, 0));
+        assert!(!Deg(f32::NAN).approx_eq_ulps(f32::NAN, u32::MAX));
+    }
+
+    #[test]
+    fn test_clamp_to_range() {
+        let (lo, hi) = (Deg(-45.), Deg(45.));
+        assert_eq!(Deg(-60.).clamp_to_range(lo, hi), Deg(-45.));
+        assert_eq!(Deg(10.).clamp_to_range(lo, hi), Deg(10.));
+        assert_eq!(Deg(60.).clamp_to_range(lo, hi), Deg(45.));
+        assert_eq!(Deg(350.).clamp_to_range(lo, hi), Deg(45.));
+        assert_eq!(Deg(350.).wrap_then_clamp(lo, hi), Deg(-10.));
+    }
+
+    #[test]
+    fn test_to_cardinal() {
+        assert_eq!(Deg(0.).to_cardinal(), Cardinal::North);
+        assert_eq!(Deg(44.9).to_cardinal(), Cardinal::North);
+        assert_eq!(Deg(45.).to_cardinal(), Cardinal::East);
+        assert_eq!(Deg(134.9).to_cardinal(), Cardinal::East);
+        assert_eq!(Deg(135.).to_cardinal(), Cardinal::South);
+        assert_eq!(Deg(225.).to_cardinal(), Cardinal::West);
+        assert_eq!(Deg(314.9).to_cardinal(), Cardinal::West);
+        assert_eq!(Deg(315.).to_cardinal(), Cardinal::North);
+        assert_eq!(Deg(-90.).to_cardinal(), Cardinal::West);
+        assert_eq!(Deg(f64::NAN).to_cardinal(), Cardinal::North);
+    }
+
+    #[test]
+    fn test_exact_quadrant() {
+        use std::f64::consts::{FRAC_PI_2, PI};
+
+        assert_eq!(Deg(0.).to_radians_exact_quadrant(), 0.);
+        assert_eq!(Deg(90.).to_radians_exact_quadrant(), FRAC_PI_2);
+        assert_eq!(Deg(180.).to_radians_exact_quadrant(), PI);
+        assert_eq!(Deg(270.).to_radians_exact_quadrant(), 3. * FRAC_PI_2);
+
+        // The plain conversions drift on these.
+        assert_ne!(Rad(Deg(450_f32).to_radians()).to_degrees(), 450.);
+        assert_ne!(Rad(Deg(990_f64).to_radians()).to_degrees(), 990.);
+
+        for quarters in -40..=40 {
+            let deg = Deg(f64::from(quarters) * 90.);
+            let rad = Rad(deg.to_radians_exact_quadrant());
+            assert_eq!(rad.to_degrees_exact_quadrant(), deg.value());
+
+            let deg = Deg(quarters as f32 * 90.);
+            let rad = Rad(deg.to_radians_exact_quadrant());
+            assert_eq!(rad.to_degrees_exact_quadrant(), deg.value());
+        }
+
+        assert_eq!(Deg(45.).to_radians_exact_quadrant(), Deg(45.).to_radians());
+        assert_eq!(Rad(1.).to_degrees_exact_quadrant(), Rad(1.).to_degrees());
+    }
+
+    #[test]
+    fn test_from_points() {
+        assert_eq!(Deg::from_points([1., 1.], [4., 1.]), Deg(0.));
+        assert_eq!(Deg::from_points([4., 1.], [1., 1.]), Deg(180.));
+        assert_eq!(Deg::from_points([1., 1.], [1., 3.]), Deg(90.));
+        assert_eq!(Deg::from_points([1., 3.], [1., 1.]), Deg(-90.));
+        assert!(Deg::from_points([0., 0.], [2., 2.]).approx_eq(45.));
+        assert_eq!(Deg::from_points([2., 2.], [2., 2.]), Deg(0.));
+        assert_eq!(Deg::from_points([-0., 0.], [0., -0.]), Deg(0.));
+
+        assert_eq!(
+            Rad::from_points([0., 0.], [0., 5.]),
+            Rad(std::f64::consts::FRAC_PI_2)
+        );
+        assert_eq!(Rad::from_points([3., 3.], [3., 3.]), Rad(0.));
+    }
+
+    #[test]
+    fn test_approx_eq_as_f64() {
+        let a = 0.5_f32;
+        let b = f32::from_bits(a.to_bits() + 1);
+
+        // The f32 epsilon is loose enough to call these equal, the f64 one isn't.
+        assert!(Deg(a).approx_eq(b));
+        assert!(!Deg(f64::from(a)).approx_eq(f64::from(b)));
+
+        assert!(!Deg(a).approx_eq_as_f64(b));
+        assert!(!Deg(f64::from(a)).approx_eq_as_f64(f64::from(b)));
+        assert!(Deg(a).approx_eq_as_f64(a));
+        assert!(Deg(f64::from(a)).approx_eq_as_f64(f64::from(a)));
+        assert!(!Rad(a).approx_eq_as_f64(b));
+    }
+
+    #[test]
+    fn test_normalize_all() {
+        let angles = [Deg(-90_f64), Deg(0.), Deg(370.), Deg(720.), Deg(-0.)];
+        let normalized = Deg::normalize_all(&angles);
+        assert_eq!(normalized.len(), angles.len());
+        for (normalized, angle) in normalized.iter().zip(&angles) {
+            assert_eq!(
+                normalized.value().to_bits(),
+                angle.normalized().value().to_bits()
+            );
+        }
+        assert_eq!(normalized[2], Deg(10.));
+    }
+
+    #[test]
+    fn test_spherical() {
+        let close = |a: [f64; 3], b: [f64; 3]| (0..3).all(|i| (a[i] - b[i]).abs() < 1e-12);
+
+        assert!(close(
+            spherical_to_cartesian(Deg(0.), Deg(0.)),
+            [1., 0., 0.]
+        ));
+        assert!(close(
+            spherical_to_cartesian(Deg(90.), Deg(0.)),
+            [0., 1., 0.]
+        ));
+        assert!(close(
+            spherical_to_cartesian(Deg(30.), Deg(90.)),
+            [0., 0., 1.]
+        ));
+
+        let (azimuth, elevation) = cartesian_to_spherical([0., 0., -2.]);
+        assert_eq!(azimuth, Rad(0.));
+        assert!(Deg(-90.).approx_eq(elevation));
+
+        for (az, el) in [
+            (0., 0.),
+            (45., 30.),
+            (-120., -60.),
+            (180., 10.),
+            (0., 90.),
+            (0., -90.),
+        ] {
+            let v = spherical_to_cartesian(Deg(az), Deg(el));
+            let (azimuth, elevation) = cartesian_to_spherical(v);
+            assert!(close(spherical_to_cartesian(azimuth, elevation), v));
+            assert!((Deg::from(elevation).value() - el).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_normalized_conversions() {
+        use std::f64::consts::{FRAC_PI_2, PI};
+
+        assert!(inexact_eq!(Deg(450.).to_radians_normalized(), FRAC_PI_2));
+        assert!(inexact_eq!(
+            Deg(-90.).to_radians_normalized(),
+            3. * FRAC_PI_2
+        ));
+        assert_eq!(Deg(720.).to_radians_normalized(), 0.);
+
+        assert!((Rad(5. * PI / 2.).to_degrees_normalized() - 90.).abs() < 1e-12);
+        assert!((Rad(-PI / 2.).to_degrees_normalized() - 270.).abs() < 1e-12);
+        assert_eq!(Rad(-2. * PI).to_degrees_normalized(), 0.);
+    }
+
+    #[test]
+    fn test_rotated_by() {
+        use std::f64::consts::FRAC_PI_2;
+
+        let heading = Deg(300.0_f64);
+        assert!(inexact_eq!(heading.rotated_by(Rad(FRAC_PI_2)).0, 30.));
+        assert!(inexact_eq!(heading.rotated_by(Rad(-FRAC_PI_2)).0, 210.));
+        assert_eq!(heading.rotated_by(60.), Deg(0.));
+        assert_eq!(Deg(10.0_f64).rotated_by(Deg(-20.)), Deg(350.));
+    }
+
+    #[test]
+    fn test_is_between() {
+        assert!(Deg(45.).is_between(Deg(0.), Deg(90.)));
+        assert!(Deg(90.).is_between(Deg(0.), Deg(90.)));
+        assert!(!Deg(f64::NAN).is_between(Deg(0.), Deg(90.)));
+
+        // Raw values don't wrap, unlike an arc.
+        let arc = DegRange::new(Deg(350.), Deg(10.));
+        assert!(arc.contains(Deg(370.)));
+        assert!(!Deg(370.).is_between(Deg(350.), Deg(10.)));
+        assert!(!Deg(0.).is_between(Deg(350.), Deg(10.)));
+        assert!(Deg(400.).is_between(Deg(-720.), Deg(720.)));
+        assert!(!arc.contains(Deg(400.)));
+
+        assert!(Rad(7.).is_between(Rad(0.), Rad(10.)));
+        assert!(!Rad(-0.5).is_between(Rad(0.), Rad(10.)));
+    }
+
+    #[test]
+    fn test_new_in_range() {
+        assert_eq!(Deg::new_in_range(45., -90., 90.), Ok(Deg(45.)));
+        assert_eq!(Deg::new_in_range(-90., -90., 90.), Ok(Deg(-90.)));
+        assert_eq!(Deg::new_in_range(90., -90., 90.), Ok(Deg(90.)));
+        assert_eq!(Deg::new_in_range(90.5, -90., 90.), Err(RangeError(())));
+        assert_eq!(Deg::new_in_range(-120., -90., 90.), Err(RangeError(())));
+        assert!(Deg::new_in_range(f64::NAN, -90., 90.).is_err());
+        assert_eq!(RangeError(()).to_string(), "angle out of range");
+    }
+
+    #[test]
+    fn test_smooth_damp() {
+        let target = Deg(10.0_f64);
+        let mut heading = Deg(350.0_f64);
+        let mut velocity = 0.;
+        for _ in 0..300 {
+            heading = heading.smooth_damp(target, &mut velocity, 0.3, 1. / 60.);
+            // Stays on the short 20° arc, never swinging the long way.
+            assert!(Deg(350.).ccw_distance_to(heading).0 <= 20. + 1e-9);
+            assert!(velocity >= 0.);
+        }
+        assert!(heading.approx_eq_wrapped(target, 1e-3));
+
+        let mut velocity = 0.;
+        let step = Deg(10.0_f64).smooth_damp(Deg(350.), &mut velocity, 0.3, 1. / 60.);
+        assert!(step.0 < 10. && step.0 > 0.);
+        assert!(velocity < 0.);
+
+        // Starting on the target with leftover velocity stays put.
+        let mut velocity = 50.;
+        let step = Deg(90.0_f64).smooth_damp(Deg(90.), &mut velocity, 1., 0.1);
+        assert_eq!(step, Deg(90.));
+        assert_eq!(velocity, 0.);
+
+        // Fast enough to pass the target in one step, from either side.
+        let mut velocity = 1000.;
+        let step = Deg(80.0_f64).smooth_damp(Deg(90.), &mut velocity, 1., 0.1);
+        assert_eq!(step, Deg(90.));
+        let mut velocity = -1000.;
+        let step = Deg(100.0_f64).smooth_damp(Deg(90.), &mut velocity, 1., 0.1);
+        assert_eq!(step, Deg(90.));
+    }
+
+    #[test]
+    fn test_bits_round_trip() {
+        for value in [0.0_f32, -0.0, 45.5, -1e-42, f32::INFINITY, f32::MAX] {
+            let bits = Deg(value).to_bits();
+            assert_eq!(bits, value.to_bits());
+            assert_eq!(Deg::<f32>::from_bits(bits).to_bits(), bits);
+        }
+        for value in [0.0_f64, -0.0, 359.999, -5e-324, f64::NEG_INFINITY] {
+            let bits = Deg(value).to_bits();
+            assert_eq!(Deg::<f64>::from_bits(bits).to_bits(), bits);
+        }
+
+        // NaN payloads survive, even though NaN never compares equal.
+        let nan = Deg::<f32>::from_bits(0x7fc0_1234);
+        assert!(nan.is_nan());
+        assert_eq!(nan.to_bits(), 0x7fc0_1234);
+        let nan = Deg::<f64>::from_bits(0xfff8_0000_dead_beef);
+        assert!(nan.is_nan());
+        assert_eq!(nan.to_bits(), 0xfff8_0000_dead_beef);
+    }
+
+    #[test]
+    fn test_unwrap_near() {
+        let samples = [350., 355., 2., 8., 15., 358., 340.];
+        let expected = [350., 355., 362., 368., 375., 358., 340.];
+        let mut previous = Deg(samples[0]);
+        for (&sample, &expected) in samples.iter().zip(expected.iter()) {
+            let unwrapped = Deg(sample).unwrap_near(previous);
+            assert!(inexact_eq!(unwrapped.0, expected));
+            assert!((unwrapped.0 - previous.0).abs() <= 180.);
+            previous = unwrapped;
+        }
+
+        assert_eq!(Deg(10.).unwrap_near(Deg(-720.)), Deg(-710.));
+        assert_eq!(Deg(725.).unwrap_near(Deg(0.)), Deg(5.));
+    }
+
+    #[test]
+    fn test_par_to_radians() {
+        let angles: Vec<Deg<f64>> = (0..100_000).map(|i| Deg(i as f64 * 0.37 - 5000.)).collect();
+        let parallel = Deg::par_to_radians(&angles);
+        assert_eq!(parallel.len(), angles.len());
+        for (angle, rad) in angles.iter().zip(parallel) {
+            assert_eq!(angle.to_radians(), rad);
+        }
+        assert!(Deg::<f32>::par_to_radians(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_reflect_off_normal() {
+        // Floor, normal pointing up.
+        assert_eq!(Deg(315.).reflect_off_normal(Deg(90.)), Deg(45.));
+        assert_eq!(Deg(225.).reflect_off_normal(Deg(90.)), Deg(135.));
+        // Ceiling, normal pointing down.
+        assert_eq!(Deg(45.).reflect_off_normal(Deg(270.)), Deg(315.));
+        // The normal's orientation doesn't matter.
+        assert_eq!(Deg(45.).reflect_off_normal(Deg(90.)), Deg(315.));
+        // Head on into a wall bounces straight back.
+        assert_eq!(Deg(0.).reflect_off_normal(Deg(180.)), Deg(180.));
+    }
+
+    #[test]
+    fn test_mils() {
+        use std::f64::consts::{FRAC_PI_2, PI};
+
+        assert_eq!(Deg(90.).to_mils(), 1600.);
+        assert_eq!(Deg(360.).to_mils(), 6400.);
+        assert_eq!(Deg::from_mils(1600.), Deg(90.));
+        assert_eq!(Deg::from_mils(Deg(12.3).to_mils()), Deg(12.3));
+
+        assert!(inexact_eq!(Rad(FRAC_PI_2).to_mils(), 1600.));
+        assert!(inexact_eq!(Rad(2. * PI).to_mils(), 6400.));
+        assert!(inexact_eq!(Rad::from_mils(1600.).0, FRAC_PI_2));
+        assert!(inexact_eq!(Rad::from_mils(Rad(0.7).to_mils()).0, 0.7));
+    }
+
+    #[test]
+    fn test_accumulate_clamped() {
+        let max = Deg(1080.);
+        let mut total = Deg(0.);
+
+        assert_eq!(
+            Deg::accumulate_clamped(&mut total, Deg(720.), max),
+            Deg(720.)
+        );
+        assert_eq!(total, Deg(720.));
+
+        assert_eq!(
+            Deg::accumulate_clamped(&mut total, Deg(500.), max),
+            Deg(360.)
+        );
+        assert_eq!(total, max);
+
+        assert_eq!(Deg::accumulate_clamped(&mut total, Deg(90.), max), Deg(0.));
+        assert_eq!(total, max);
+
+        assert_eq!(
+            Deg::accumulate_clamped(&mut total, Deg(-180.), max),
+            Deg(-180.)
+        );
+        assert_eq!(total, Deg(900.));
+    }
+
+    #[test]
+    fn test_shortest_direction_to() {
+        assert_eq!(
+            Deg(350.).shortest_direction_to(Deg(10.)),
+            (RotationDir::CounterClockwise, Deg(20.))
+        );
+        assert_eq!(
+            Deg(10.).shortest_direction_to(Deg(350.)),
+            (RotationDir::Clockwise, Deg(20.))
+        );
+        assert_eq!(
+            Deg(90.).shortest_direction_to(Deg(450.)),
+            (RotationDir::None, Deg(0.))
+        );
+        assert_eq!(
+            Deg(0.).shortest_direction_to(Deg(180.)),
+            (RotationDir::Clockwise, Deg(180.))
+        );
+        assert_eq!(
+            Deg(270.).shortest_direction_to(Deg(90.)),
+            (RotationDir::Clockwise, Deg(180.))
+        );
+    }
+
+    #[test]
+    fn test_assert_angle_eq() {
+        use std::f64::consts::PI;
+
+        assert_angle_eq!(Deg(180_f64), Rad(PI), 1e-12);
+        assert_angle_eq!(Deg(90_f32), Deg(90_f64), 0.);
+        assert_angle_eq!(Rad(1_f32), Rad(1.0000001_f32), 1e-6);
+    }
+
+    #[test]
+    #[should_panic(expected = "left: 90° (1.5707963267948966 rad)")]
+    fn test_assert_angle_eq_fails() {
+        assert_angle_eq!(Deg(90_f64), Deg(91_f64), 1e-3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_assert_angle_eq_nan() {
+        assert_angle_eq!(Deg(f64::NAN), Deg(f64::NAN), 1.);
+    }
+
+    #[test]
+    fn test_q16_16() {
+        assert_eq!(Deg(90.).to_q16_16(), 90 << 16);
+        assert_eq!(Deg(-0.5).to_q16_16(), -(1 << 15));
+        assert_eq!(Deg::from_q16_16(90 << 16), Deg(90.));
+        assert_eq!(Deg::<f64>::from_q16_16(i32::MIN), Deg(-32768.));
+
+        for value in [0., 1., -1., 45.25, 359.999, -180., 12345.678] {
+            let round_trip = Deg::<f64>::from_q16_16(Deg(value).to_q16_16());
+            assert!((round_trip.0 - value).abs() <= 0.5 / 65536.);
+        }
+
+        assert_eq!(Deg(1e6).to_q16_16(), i32::MAX);
+        assert_eq!(Deg(-1e6).to_q16_16(), i32::MIN);
+        assert_eq!(Deg(f64::NAN).to_q16_16(), 0);
+
+        // f32 can't hold every raw value, but lands on the nearest one.
+        for raw in [
+            1189442682,
+            i32::MAX - 1000,
+            i32::MAX,
+            i32::MIN + 77,
+            16_777_217,
+        ] {
+            let deg = Deg::<f32>::from_q16_16(raw);
+            assert_eq!(deg, Deg(raw as f32 / 65536.));
+            assert_eq!(deg.to_q16_16(), raw as f32 as i32);
+            assert_eq!(Deg::<f32>::from_q16_16(deg.to_q16_16()), deg);
+        }
+        assert_eq!(Deg::<f32>::from_q16_16(1189442682), Deg(18149.455));
+    }
+
+    #[test]
+    fn test_trig_signs() {
+        assert_eq!(Deg(45.).trig_signs(), (1, 1));
+        assert_eq!(Deg(135.).trig_signs(), (1, -1));
+        assert_eq!(Deg(225.).trig_signs(), (-1, -1));
+        assert_eq!(Deg(315.).trig_signs(), (-1, 1));
+
+        assert_eq!(Deg(0.).trig_signs(), (0, 1));
+        assert_eq!(Deg(90.).trig_signs(), (1, 0));
+        assert_eq!(Deg(180.).trig_signs(), (0, -1));
+        assert_eq!(Deg(270.).trig_signs(), (-1, 0));
+        assert_eq!(Deg(-90.).trig_signs(), (-1, 0));
+        assert_eq!(Deg(720.).trig_signs(), (0, 1));
+        assert_eq!(Deg(f64::NAN).trig_signs(), (0, 0));
+
+        for i in 0..72 {
+            let angle = Deg(i as f64 * 5. + 2.5);
+            let rad = angle.to_radians();
+            let expected = (rad.sin().signum() as i8, rad.cos().signum() as i8);
+            assert_eq!(angle.trig_signs(), expected);
+        }
+    }
+
+    #[test]
+    fn test_from_dot() {
+        use std::f64::consts::{FRAC_PI_2, FRAC_PI_3, PI};
+
+        assert_eq!(Rad::from_dot(0., 2., 3.), Rad(FRAC_PI_2));
+        assert!(inexact_eq!(Rad::from_dot(1., 1., 2.).0, FRAC_PI_3));
+        assert_eq!(Rad::from_dot(-6., 2., 3.), Rad(PI));
+
+        // Round-off pushing the ratio just past 1.0 would make acos NaN.
+        let dot = 1.0000000000000002;
+        assert!(dot.acos().is_nan());
+        assert_eq!(Rad::from_dot(dot, 1., 1.), Rad(0.));
+        assert_eq!(Rad::from_dot(-dot, 1., 1.), Rad(PI));
+
+        assert!(Rad::from_dot(0., 0., 1.).is_nan());
+    }
+
+    #[test]
+    fn test_equivalent_undirected() {
+        let eps = Deg(1e-9);
+        assert!(Deg(30.).equivalent_undirected(Deg(210.), eps));
+        assert!(Deg(210.).equivalent_undirected(Deg(30.), eps));
+        assert!(Deg(30.).equivalent_undirected(Deg(-150.), eps));
+        assert!(Deg(30.).equivalent_undirected(Deg(390.), eps));
+        assert!(!Deg(30.).equivalent_undirected(Deg(120.), eps));
+        assert!(Deg(0.).equivalent_undirected(Deg(179.5), Deg(1.)));
+        assert!(!Deg(0.).equivalent_undirected(Deg(178.), Deg(1.)));
+    }
+
+    #[test]
+    fn test_representation_error() {
+        for exact in [0., -0., 0.5, 90., 359.75, -180., 1e22, 0.0078125] {
+            assert_eq!(Deg::<f64>::representation_error(exact), 0.);
+        }
+
+        // Reference values from exact rational arithmetic.
+        let cases = [
+            (0.1, -5.551115123125783e-18),
+            (-0.1, 5.551115123125783e-18),
+            (359.9, 2.2737367544323207e-14),
+            (12.345, -6.394884621840901e-16),
+            (1e-5, -8.180305391403131e-22),
+            (123456789012345.6, 0.00625),
+        ];
+        for (nominal, expected) in cases {
+            let error = Deg::<f64>::representation_error(nominal);
+            assert!(
+                (error - expected).abs() <= expected.abs() * 1e-12,
+                "{}",
+                nominal
+            );
+        }
+
+        assert!(Deg::<f64>::representation_error(1e-30).is_nan());
+        assert!(Deg::<f64>::representation_error(f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_golden_angle_sequence() {
+        let golden = 180. * (3. - 5_f64.sqrt());
+        let angles: Vec<Deg<f64>> = Deg::golden_angle_sequence(6).collect();
+        assert_eq!(angles.len(), 6);
+        for (i, angle) in angles.iter().enumerate() {
+            assert!((angle.0 - (i as f64 * golden) % 360.).abs() < 1e-12);
+        }
+        assert!((angles[1].0 - 137.50776405003785).abs() < 1e-12);
+        assert!((angles[2].0 - 275.0155281000757).abs() < 1e-12);
+        assert!((angles[3].0 - 52.52329215011355).abs() < 1e-12);
+        assert_eq!(Deg::<f32>::golden_angle_sequence(0).count(), 0);
+
+        // Far along the sequence, against multiples of the true golden
+        // angle worked out to high precision.
+        let sequence: Vec<Deg<f32>> = Deg::golden_angle_sequence(100_000).collect();
+        for (i, expected) in [(10_000, 237.64050037854646), (99_999, 78.89723973542678)] {
+            assert!((sequence[i].0 as f64 - expected).abs() < 1e-4, "{}", i);
+        }
+    }
+
+    #[test]
+    fn test_special_angles() {
+        assert!((Deg::<f64>::golden_angle().0 - 137.50776405003785).abs() < 1e-12);
+        assert!((Deg::<f64>::magic_angle().0 - 54.735610317245346).abs() < 1e-12);
+        assert!((Deg::<f64>::tetrahedral_angle().0 - 109.47122063449069).abs() < 1e-12);
+        assert!((Deg::<f32>::golden_angle().0 - 137.50777).abs() < 1e-4);
+
+        // The tetrahedral angle is twice the magic angle.
+        let double = Deg::<f64>::magic_angle().0 * 2.;
+        assert!((double - Deg::<f64>::tetrahedral_angle().0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_clamp_arc() {
+        let (start, end) = (Deg(350.), Deg(20.));
+        assert_eq!(Deg(0.).clamp_arc(start, end), Deg(0.));
+        assert_eq!(Deg(355.).clamp_arc(start, end), Deg(355.));
+        assert_eq!(Deg(20.).clamp_arc(start, end), Deg(20.));
+
+        // Just outside each end.
+        assert_eq!(Deg(345.).clamp_arc(start, end), start);
+        assert_eq!(Deg(25.).clamp_arc(start, end), end);
+        assert_eq!(Deg(-15.).clamp_arc(start, end), start);
+
+        // Either side of the point opposite the arc's middle, at 185°.
+        assert_eq!(Deg(190.).clamp_arc(start, end), start);
+        assert_eq!(Deg(180.).clamp_arc(start, end), end);
+        assert_eq!(Deg(185.).clamp_arc(start, end), start);
+
+        // A plain arc that doesn't cross the seam.
+        assert_eq!(Deg(100.).clamp_arc(Deg(10.), Deg(90.)), Deg(90.));
+        assert_eq!(Deg(5.).clamp_arc(Deg(10.), Deg(90.)), Deg(10.));
+    }
+
+    #[test]
+    fn test_wedge_bisector() {
+        assert_eq!(Deg::wedge_bisector(Deg(10.), Deg(50.)), Deg(30.));
+        assert_eq!(Deg::wedge_bisector(Deg(350.), Deg(30.)), Deg(10.));
+        assert_eq!(Deg::wedge_bisector(Deg(0.), Deg(270.)), Deg(135.));
+        assert_eq!(Deg::wedge_bisector(Deg(270.), Deg(0.)), Deg(315.));
+        assert_eq!(Deg::wedge_bisector(Deg(200.), Deg(100.)), Deg(330.));
+        assert_eq!(Deg::wedge_bisector(Deg(45.), Deg(405.)), Deg(45.));
+    }
+
+    #[test]
+    fn test_turns_and_remainder() {
+        assert_eq!(Deg(810.).turns_and_remainder(), (2, Deg(90.)));
+        assert_eq!(Deg(45.).turns_and_remainder(), (0, Deg(45.)));
+        assert_eq!(Deg(360.).turns_and_remainder(), (1, Deg(0.)));
+        assert_eq!(Deg(-90.).turns_and_remainder(), (-1, Deg(270.)));
+        assert_eq!(Deg(-720.).turns_and_remainder(), (-2, Deg(0.)));
+        assert_eq!(Deg(-1e-20).turns_and_remainder(), (0, Deg(0.)));
+
+        assert_eq!(
+            Deg(360_000_090.).turns_and_remainder(),
+            (1_000_000, Deg(90.))
+        );
+        assert_eq!(
+            Deg(-360_000_090.).turns_and_remainder(),
+            (-1_000_001, Deg(270.))
+        );
+        assert_eq!(Deg(1e300).turns_and_remainder().0, i64::MAX);
+        assert_eq!(Deg(f64::NAN).turns_and_remainder().0, 0);
+    }
+
+    #[test]
+    fn test_from_rotation_matrix_2d() {
+        use std::f64::consts::PI;
+
+        let matrix = |angle: f64| {
+            let (sin, cos) = angle.sin_cos();
+            [[cos, -sin], [sin, cos]]
+        };
+        for angle in [0., 0.5, -0.5, 2., -3., PI / 2.] {
+            let recovered = Rad::from_rotation_matrix_2d(matrix(angle));
+            assert!((recovered.0 - angle).abs() < 1e-12, "{}", angle);
+        }
+
+        assert_eq!(
+            Rad::from_rotation_matrix_2d([[-1., 0.], [-0., -1.]]),
+            Rad(PI)
+        );
+
+        let noisy = [[2.1, -2.09], [2.1, 2.12]];
+        assert!((Rad::from_rotation_matrix_2d(noisy).0 - PI / 4.).abs() < 1e-12);
+    }
 }
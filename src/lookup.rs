@@ -0,0 +1,157 @@
+//! Precomputed sine and cosine for whole degrees.
+//!
+//! The table is generated ahead of time, so lookups need no runtime
+//! trigonometry. This suits targets without a floating point unit, where
+//! `sin` and `cos` are expensive software routines.
+//!
+//! Table entries are the `f32` nearest to the true sine, so they are
+//! within `1e-7` of it. The interpolated lookups are within `4e-5`.
+
+/// `sin(d°)` for each whole degree `d` in [0, 360).
+#[rustfmt::skip]
+static SIN_TABLE: [f32; 360] = [
+    0.0, 0.017452406, 0.034899496, 0.052335955, 0.06975647, 0.087155744, 0.104528464, 0.12186934,
+    0.1391731, 0.15643446, 0.17364818, 0.190809, 0.20791169, 0.22495106, 0.2419219, 0.25881904,
+    0.27563736, 0.2923717, 0.309017, 0.32556817, 0.34202015, 0.35836795, 0.37460658, 0.39073113,
+    0.40673664, 0.42261827, 0.43837115, 0.4539905, 0.46947157, 0.4848096, 0.5, 0.5150381,
+    0.52991927, 0.54463905, 0.5591929, 0.57357645, 0.58778524, 0.60181504, 0.6156615, 0.6293204,
+    0.64278764, 0.656059, 0.6691306, 0.6819984, 0.6946584, 0.70710677, 0.7193398, 0.7313537,
+    0.7431448, 0.7547096, 0.76604444, 0.777146, 0.7880108, 0.7986355, 0.809017, 0.81915206,
+    0.82903755, 0.83867055, 0.8480481, 0.8571673, 0.8660254, 0.8746197, 0.88294756, 0.8910065,
+    0.89879405, 0.9063078, 0.9135454, 0.92050487, 0.92718387, 0.9335804, 0.9396926, 0.94551855,
+    0.95105654, 0.9563047, 0.9612617, 0.9659258, 0.9702957, 0.97437006, 0.9781476, 0.98162717,
+    0.9848077, 0.98768836, 0.99026805, 0.99254614, 0.9945219, 0.9961947, 0.9975641, 0.9986295,
+    0.99939084, 0.9998477, 1.0, 0.9998477, 0.99939084, 0.9986295, 0.9975641, 0.9961947,
+    0.9945219, 0.99254614, 0.99026805, 0.98768836, 0.9848077, 0.98162717, 0.9781476, 0.97437006,
+    0.9702957, 0.9659258, 0.9612617, 0.9563047, 0.95105654, 0.94551855, 0.9396926, 0.9335804,
+    0.92718387, 0.92050487, 0.9135454, 0.9063078, 0.89879405, 0.8910065, 0.88294756, 0.8746197,
+    0.8660254, 0.8571673, 0.8480481, 0.83867055, 0.82903755, 0.81915206, 0.809017, 0.7986355,
+    0.7880108, 0.777146, 0.76604444, 0.7547096, 0.7431448, 0.7313537, 0.7193398, 0.70710677,
+    0.6946584, 0.6819984, 0.6691306, 0.656059, 0.64278764, 0.6293204, 0.6156615, 0.60181504,
+    0.58778524, 0.57357645, 0.5591929, 0.54463905, 0.52991927, 0.5150381, 0.5, 0.4848096,
+    0.46947157, 0.4539905, 0.43837115, 0.42261827, 0.40673664, 0.39073113, 0.37460658, 0.35836795,
+    0.34202015, 0.32556817, 0.309017, 0.2923717, 0.27563736, 0.25881904, 0.2419219, 0.22495106,
+    0.20791169, 0.190809, 0.17364818, 0.15643446, 0.1391731, 0.12186934, 0.104528464, 0.087155744,
+    0.06975647, 0.052335955, 0.034899496, 0.017452406, 0.0, -0.017452406, -0.034899496, -0.052335955,
+    -0.06975647, -0.087155744, -0.104528464, -0.12186934, -0.1391731, -0.15643446, -0.17364818, -0.190809,
+    -0.20791169, -0.22495106, -0.2419219, -0.25881904, -0.27563736, -0.2923717, -0.309017, -0.32556817,
+    -0.34202015, -0.35836795, -0.37460658, -0.39073113, -0.40673664, -0.42261827, -0.43837115, -0.4539905,
+    -0.46947157, -0.4848096, -0.5, -0.5150381, -0.52991927, -0.54463905, -0.5591929, -0.57357645,
+    -0.58778524, -0.60181504, -0.6156615, -0.6293204, -0.64278764, -0.656059, -0.6691306, -0.6819984,
+    -0.6946584, -0.70710677, -0.7193398, -0.7313537, -0.7431448, -0.7547096, -0.76604444, -0.777146,
+    -0.7880108, -0.7986355, -0.809017, -0.81915206, -0.82903755, -0.83867055, -0.8480481, -0.8571673,
+    -0.8660254, -0.8746197, -0.88294756, -0.8910065, -0.89879405, -0.9063078, -0.9135454, -0.92050487,
+    -0.92718387, -0.9335804, -0.9396926, -0.94551855, -0.95105654, -0.9563047, -0.9612617, -0.9659258,
+    -0.9702957, -0.97437006, -0.9781476, -0.98162717, -0.9848077, -0.98768836, -0.99026805, -0.99254614,
+    -0.9945219, -0.9961947, -0.9975641, -0.9986295, -0.99939084, -0.9998477, -1.0, -0.9998477,
+    -0.99939084, -0.9986295, -0.9975641, -0.9961947, -0.9945219, -0.99254614, -0.99026805, -0.98768836,
+    -0.9848077, -0.98162717, -0.9781476, -0.97437006, -0.9702957, -0.9659258, -0.9612617, -0.9563047,
+    -0.95105654, -0.94551855, -0.9396926, -0.9335804, -0.92718387, -0.92050487, -0.9135454, -0.9063078,
+    -0.89879405, -0.8910065, -0.88294756, -0.8746197, -0.8660254, -0.8571673, -0.8480481, -0.83867055,
+    -0.82903755, -0.81915206, -0.809017, -0.7986355, -0.7880108, -0.777146, -0.76604444, -0.7547096,
+    -0.7431448, -0.7313537, -0.7193398, -0.70710677, -0.6946584, -0.6819984, -0.6691306, -0.656059,
+    -0.64278764, -0.6293204, -0.6156615, -0.60181504, -0.58778524, -0.57357645, -0.5591929, -0.54463905,
+    -0.52991927, -0.5150381, -0.5, -0.4848096, -0.46947157, -0.4539905, -0.43837115, -0.42261827,
+    -0.40673664, -0.39073113, -0.37460658, -0.35836795, -0.34202015, -0.32556817, -0.309017, -0.2923717,
+    -0.27563736, -0.25881904, -0.2419219, -0.22495106, -0.20791169, -0.190809, -0.17364818, -0.15643446,
+    -0.1391731, -0.12186934, -0.104528464, -0.087155744, -0.06975647, -0.052335955, -0.034899496, -0.017452406,
+];
+
+/// Table index for a whole number of degrees, wrapped into [0, 360).
+#[inline]
+fn index(deg: i32) -> usize {
+    deg.rem_euclid(360) as usize
+}
+
+/// Sine of a whole number of degrees, read from the table.
+#[inline]
+pub fn sin_deg_table(deg: i32) -> f32 {
+    SIN_TABLE[index(deg)]
+}
+
+/// Cosine of a whole number of degrees, read from the table.
+#[inline]
+pub fn cos_deg_table(deg: i32) -> f32 {
+    SIN_TABLE[(index(deg) + 90) % 360]
+}
+
+/// Sine of a fractional number of degrees, linearly interpolated between
+/// the neighbouring table entries. Non-finite input gives NaN.
+pub fn sin_deg_lerp(deg: f32) -> f32 {
+    if !deg.is_finite() {
+        return f32::NAN;
+    }
+    let deg = deg.rem_euclid(360.0);
+    let whole = deg.floor();
+    let t = deg - whole;
+    let i = whole as usize % 360;
+    let a = SIN_TABLE[i];
+    let b = SIN_TABLE[(i + 1) % 360];
+    a + (b - a) * t
+}
+
+/// Cosine of a fractional number of degrees, linearly interpolated between
+/// the neighbouring table entries. Non-finite input gives NaN.
+#[inline]
+pub fn cos_deg_lerp(deg: f32) -> f32 {
+    sin_deg_lerp(deg + 90.0)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_table_matches_sin() {
+        for deg in 0..360 {
+            let expected = (deg as f64).to_radians().sin() as f32;
+            assert!(
+                (sin_deg_table(deg) - expected).abs() <= 1e-7,
+                "sin {}°",
+                deg
+            );
+            let expected = (deg as f64).to_radians().cos() as f32;
+            assert!(
+                (cos_deg_table(deg) - expected).abs() <= 1e-7,
+                "cos {}°",
+                deg
+            );
+        }
+        assert_eq!(sin_deg_table(90), 1.);
+        assert_eq!(sin_deg_table(180), 0.);
+        assert_eq!(cos_deg_table(180), -1.);
+    }
+
+    #[test]
+    fn test_table_wraps() {
+        assert_eq!(sin_deg_table(-90), -1.);
+        assert_eq!(sin_deg_table(450), 1.);
+        assert_eq!(
+            sin_deg_table(i32::MIN),
+            sin_deg_table(i32::MIN.rem_euclid(360))
+        );
+        assert_eq!(cos_deg_table(-360), 1.);
+    }
+
+    #[test]
+    fn test_lerp() {
+        let mut deg = -720.0_f32;
+        while deg < 720.0 {
+            let rad = deg.to_radians();
+            assert!(
+                (sin_deg_lerp(deg) - rad.sin()).abs() <= 4e-5,
+                "sin {}°",
+                deg
+            );
+            assert!(
+                (cos_deg_lerp(deg) - rad.cos()).abs() <= 4e-5,
+                "cos {}°",
+                deg
+            );
+            deg += 0.37;
+        }
+        assert_eq!(sin_deg_lerp(30.), sin_deg_table(30));
+        assert!(sin_deg_lerp(f32::INFINITY).is_nan());
+        assert!(sin_deg_lerp(f32::NAN).is_nan());
+    }
+}
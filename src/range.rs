@@ -0,0 +1,164 @@
+use crate::Deg;
+use num_traits::{
+    cast::FromPrimitive,
+    float::{Float, FloatConst},
+};
+
+/// An arc of the circle, running counterclockwise from `start` to `end`.
+///
+/// The arc may cross 0°, so a range from 350° to 10° covers 20°. When
+/// `start` and `end` are the same angle the range has zero width and
+/// only contains that angle.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct DegRange<N: Float> {
+    pub start: Deg<N>,
+    pub end: Deg<N>,
+}
+
+impl<N> DegRange<N>
+where
+    N: Float + FromPrimitive + FloatConst,
+{
+    #[inline]
+    pub fn new(start: Deg<N>, end: Deg<N>) -> DegRange<N> {
+        DegRange { start, end }
+    }
+
+    /// Length of the arc, in the range [0°, 360°).
+    #[inline]
+    pub fn width(&self) -> Deg<N> {
+        self.start.ccw_distance_to(self.end)
+    }
+
+    /// Angle swept from `start` to `end`, in the range [0°, 360°).
+    ///
+    /// The same as [`width`](DegRange::width), named for code that
+    /// thinks of the range as a span.
+    #[inline]
+    pub fn span(&self) -> Deg<N> {
+        self.width()
+    }
+
+    /// Whether the angle lies on the arc, including both ends.
+    #[inline]
+    pub fn contains(&self, angle: Deg<N>) -> bool {
+        self.start.ccw_distance_to(angle).0 <= self.width().0
+    }
+
+    /// Whether the two arcs share at least one angle.
+    #[inline]
+    pub fn intersects(&self, other: &DegRange<N>) -> bool {
+        self.contains(other.start) || other.contains(self.start)
+    }
+
+    /// The arc shared by both ranges, or `None` if they're disjoint.
+    ///
+    /// Two arcs that together cover more than the full circle can overlap
+    /// in two separate pieces. Only the piece beginning at `other.start`
+    /// is returned then.
+    pub fn intersection(&self, other: &DegRange<N>) -> Option<DegRange<N>> {
+        let (start, a, b) = if self.contains(other.start) {
+            (other.start, self, other)
+        } else if other.contains(self.start) {
+            (self.start, other, self)
+        } else {
+            return None;
+        };
+        let end = if start.ccw_distance_to(a.end).0 <= start.ccw_distance_to(b.end).0 {
+            a.end
+        } else {
+            b.end
+        };
+        Some(DegRange { start, end })
+    }
+}
+
+impl<N: Float> From<(Deg<N>, Deg<N>)> for DegRange<N> {
+    #[inline]
+    fn from((start, end): (Deg<N>, Deg<N>)) -> DegRange<N> {
+        DegRange { start, end }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_seam_crossing() {
+        let range = DegRange::new(Deg(350.), Deg(10.));
+        assert_eq!(range.width(), Deg(20.));
+        assert!(range.contains(Deg(0.)));
+        assert!(range.contains(Deg(355.)));
+        assert!(range.contains(Deg(370.)));
+        assert!(range.contains(Deg(350.)));
+        assert!(range.contains(Deg(10.)));
+        assert!(!range.contains(Deg(180.)));
+        assert!(!range.contains(Deg(11.)));
+    }
+
+    #[test]
+    fn test_intersects() {
+        let range = DegRange::new(Deg(350.), Deg(10.));
+        assert!(range.intersects(&DegRange::new(Deg(5.), Deg(90.))));
+        assert!(range.intersects(&DegRange::new(Deg(340.), Deg(20.))));
+        assert!(DegRange::new(Deg(340.), Deg(20.)).intersects(&range));
+        assert!(!range.intersects(&DegRange::new(Deg(20.), Deg(340.))));
+    }
+
+    #[test]
+    fn test_from_tuple() {
+        let range = DegRange::from((Deg(300.), Deg(30.)));
+        assert_eq!(range, DegRange::new(Deg(300.), Deg(30.)));
+        assert_eq!(range.span(), Deg(90.));
+
+        let range: DegRange<f64> = (Deg(30.), Deg(300.)).into();
+        assert_eq!(range.span(), Deg(270.));
+    }
+
+    #[test]
+    fn test_intersection() {
+        let range = DegRange::new(Deg(10.), Deg(90.));
+        assert_eq!(
+            range.intersection(&DegRange::new(Deg(45.), Deg(180.))),
+            Some(DegRange::new(Deg(45.), Deg(90.)))
+        );
+        assert_eq!(
+            range.intersection(&DegRange::new(Deg(20.), Deg(30.))),
+            Some(DegRange::new(Deg(20.), Deg(30.)))
+        );
+        assert_eq!(
+            range.intersection(&DegRange::new(Deg(100.), Deg(200.))),
+            None
+        );
+
+        // Crossing the seam.
+        let seam = DegRange::new(Deg(350.), Deg(30.));
+        assert_eq!(
+            seam.intersection(&DegRange::new(Deg(300.), Deg(0.))),
+            Some(DegRange::new(Deg(350.), Deg(0.)))
+        );
+        assert_eq!(
+            DegRange::new(Deg(300.), Deg(0.)).intersection(&seam),
+            Some(DegRange::new(Deg(350.), Deg(0.)))
+        );
+        assert_eq!(
+            seam.intersection(&DegRange::new(Deg(20.), Deg(340.))),
+            Some(DegRange::new(Deg(20.), Deg(30.)))
+        );
+        assert_eq!(seam.intersection(&DegRange::new(Deg(40.), Deg(340.))), None);
+
+        // Touching at a single angle.
+        assert_eq!(
+            range.intersection(&DegRange::new(Deg(90.), Deg(120.))),
+            Some(DegRange::new(Deg(90.), Deg(90.)))
+        );
+
+        // Two pieces, 180° to 270° and 0° to 90°.
+        let wide = DegRange::new(Deg(0.), Deg(270.));
+        assert_eq!(
+            wide.intersection(&DegRange::new(Deg(180.), Deg(90.))),
+            Some(DegRange::new(Deg(180.), Deg(270.)))
+        );
+    }
+}
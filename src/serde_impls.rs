@@ -0,0 +1,136 @@
+//! Serde support, enabled with the `serde` feature.
+//!
+//! Angles serialize as their inner float. Human readable formats can
+//! also deserialize them from a string with a unit suffix, like
+//! `"45deg"`, so hand edited configs can spell out the unit.
+//!
+//! Deserializing rejects NaN and infinite values, from any format, so
+//! they can't slip in at the boundary.
+use crate::{Deg, Rad};
+use num_traits::{cast::FromPrimitive, float::Float};
+use serde::de::{self, Deserialize, Deserializer, Visitor};
+use std::{fmt, marker::PhantomData, str::FromStr};
+
+/// Accepts either a number or a string parsed with `FromStr`.
+struct AngleVisitor<N, T>(PhantomData<(N, T)>);
+
+impl<'de, N, T> Visitor<'de> for AngleVisitor<N, T>
+where
+    N: Float + FromPrimitive,
+    T: From<N> + FromStr,
+{
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a number or a string like \"45deg\"")
+    }
+
+    fn visit_f64<E: de::Error>(self, value: f64) -> Result<T, E> {
+        N::from_f64(value)
+            .map(T::from)
+            .ok_or_else(|| E::invalid_value(de::Unexpected::Float(value), &self))
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<T, E> {
+        N::from_i64(value)
+            .map(T::from)
+            .ok_or_else(|| E::invalid_value(de::Unexpected::Signed(value), &self))
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<T, E> {
+        N::from_u64(value)
+            .map(T::from)
+            .ok_or_else(|| E::invalid_value(de::Unexpected::Unsigned(value), &self))
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<T, E> {
+        value
+            .parse()
+            .map_err(|_| E::invalid_value(de::Unexpected::Str(value), &self))
+    }
+}
+
+/// Binary formats aren't self describing, so they read the bare float.
+fn deserialize_angle<'de, D, N, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    N: Float + FromPrimitive + Deserialize<'de>,
+    T: From<N> + FromStr + AsRef<[N; 1]>,
+{
+    let angle: T = if deserializer.is_human_readable() {
+        deserializer.deserialize_any(AngleVisitor(PhantomData))?
+    } else {
+        N::deserialize(deserializer).map(T::from)?
+    };
+    let [value] = *angle.as_ref();
+    if value.is_finite() {
+        Ok(angle)
+    } else {
+        let value = value.to_f64().unwrap_or(f64::NAN);
+        Err(de::Error::invalid_value(
+            de::Unexpected::Float(value),
+            &"a finite angle",
+        ))
+    }
+}
+
+impl<'de, N> Deserialize<'de> for Deg<N>
+where
+    N: Float + FromPrimitive + FromStr + Deserialize<'de>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Deg<N>, D::Error> {
+        deserialize_angle::<D, N, _>(deserializer)
+    }
+}
+
+impl<'de, N> Deserialize<'de> for Rad<N>
+where
+    N: Float + FromPrimitive + FromStr + Deserialize<'de>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Rad<N>, D::Error> {
+        deserialize_angle::<D, N, _>(deserializer)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_number_or_string() {
+        let from_number: Deg<f64> = serde_json::from_str("45").unwrap();
+        let from_float: Deg<f64> = serde_json::from_str("45.0").unwrap();
+        let from_string: Deg<f64> = serde_json::from_str("\"45deg\"").unwrap();
+        assert_eq!(from_number, Deg(45.));
+        assert_eq!(from_float, Deg(45.));
+        assert_eq!(from_string, Deg(45.));
+
+        let rad: Rad<f32> = serde_json::from_str("\"1.5 rad\"").unwrap();
+        assert_eq!(rad, Rad(1.5));
+        assert!(serde_json::from_str::<Deg<f64>>("\"45rad\"").is_err());
+        assert!(serde_json::from_str::<Deg<f64>>("true").is_err());
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let json = serde_json::to_string(&Deg(22.5_f64)).unwrap();
+        assert_eq!(json, "22.5");
+        assert_eq!(serde_json::from_str::<Deg<f64>>(&json).unwrap(), Deg(22.5));
+    }
+
+    #[test]
+    fn test_rejects_non_finite() {
+        assert_eq!(serde_json::from_str::<Deg<f64>>("90.5").unwrap(), Deg(90.5));
+
+        assert!(serde_json::from_str::<Deg<f64>>("\"NaN\"").is_err());
+        assert!(serde_json::from_str::<Deg<f64>>("\"inf deg\"").is_err());
+        assert!(serde_json::from_str::<Rad<f64>>("\"-infinity rad\"").is_err());
+        // Finite in JSON, but overflows an `f32`.
+        assert!(serde_json::from_str::<Deg<f32>>("1e300").is_err());
+
+        let bytes = postcard::to_allocvec(&f64::NAN).unwrap();
+        assert!(postcard::from_bytes::<Deg<f64>>(&bytes).is_err());
+        let bytes = postcard::to_allocvec(&12.5_f64).unwrap();
+        assert_eq!(postcard::from_bytes::<Deg<f64>>(&bytes).unwrap(), Deg(12.5));
+    }
+}
@@ -0,0 +1,68 @@
+//! Rotations of plain arrays about the Z axis.
+//!
+//! ```
+//! use angle::{transform, Deg};
+//!
+//! let v = transform::rotate_z3([1., 0., 5.], Deg(90_f64));
+//! assert!(v[0].abs() < 1e-12);
+//! assert!((v[1] - 1.).abs() < 1e-12);
+//! assert_eq!(v[2], 5.);
+//! ```
+use crate::{rotate_vec2, Rad};
+use num_traits::{
+    cast::FromPrimitive,
+    float::{Float, FloatConst},
+};
+
+/// Rotate a 2D vector counterclockwise by the given angle.
+///
+/// The same as [`rotate_vec2`], named to pair with
+/// [`rotate_z3`].
+#[inline]
+pub fn rotate2<N, A>(v: [N; 2], angle: A) -> [N; 2]
+where
+    N: Float + FromPrimitive + FloatConst,
+    A: Into<Rad<N>>,
+{
+    rotate_vec2(v, angle)
+}
+
+/// Rotate a 3D vector counterclockwise about the Z axis, turning the
+/// x and y components and leaving z unchanged.
+#[inline]
+pub fn rotate_z3<N, A>(v: [N; 3], angle: A) -> [N; 3]
+where
+    N: Float + FromPrimitive + FloatConst,
+    A: Into<Rad<N>>,
+{
+    let [x, y] = rotate_vec2([v[0], v[1]], angle);
+    [x, y, v[2]]
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Deg;
+
+    fn assert_close<const D: usize>(actual: [f64; D], expected: [f64; D]) {
+        for (a, e) in actual.iter().zip(expected.iter()) {
+            assert!((a - e).abs() < 1e-12, "{:?} != {:?}", actual, expected);
+        }
+    }
+
+    #[test]
+    fn test_rotate2() {
+        assert_close(rotate2([1., 0.], Deg(90.)), [0., 1.]);
+        assert_close(rotate2([0., 1.], Deg(90.)), [-1., 0.]);
+        assert_close(rotate2([1., 0.], Rad(std::f64::consts::PI)), [-1., 0.]);
+        assert_close(rotate2([0., 1.], Deg(-90.)), [1., 0.]);
+    }
+
+    #[test]
+    fn test_rotate_z3() {
+        assert_close(rotate_z3([1., 0., 0.], Deg(90.)), [0., 1., 0.]);
+        assert_close(rotate_z3([0., 1., 0.], Deg(90.)), [-1., 0., 0.]);
+        assert_close(rotate_z3([0., 0., 1.], Deg(90.)), [0., 0., 1.]);
+        assert_close(rotate_z3([1., 0., -2.], Deg(180.)), [-1., 0., -2.]);
+    }
+}
@@ -0,0 +1,109 @@
+//! Conversion between arbitrary angular units.
+//!
+//! Each unit is described by how many radians it spans, so adding a
+//! new unit is a matter of implementing [`AngularUnit`] with one factor.
+//!
+//! ```
+//! use angle::unit::{convert, Degrees, Turns};
+//!
+//! assert_eq!(convert::<Degrees, Turns, f64>(90.), 0.25);
+//! ```
+use crate::whole;
+use num_traits::float::{Float, FloatConst};
+
+/// A unit of angle, defined by its size in radians.
+pub trait AngularUnit {
+    /// Number of radians in one of this unit.
+    fn radians_per_unit<N: Float + FloatConst>() -> N;
+}
+
+/// Degrees, 360 to a full turn.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Degrees;
+
+/// Radians, 2π to a full turn.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Radians;
+
+/// Gradians, 400 to a full turn.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Gradians;
+
+/// Full turns.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Turns;
+
+impl AngularUnit for Degrees {
+    #[inline]
+    fn radians_per_unit<N: Float + FloatConst>() -> N {
+        N::PI() / whole(180)
+    }
+}
+
+impl AngularUnit for Radians {
+    #[inline]
+    fn radians_per_unit<N: Float + FloatConst>() -> N {
+        N::one()
+    }
+}
+
+impl AngularUnit for Gradians {
+    #[inline]
+    fn radians_per_unit<N: Float + FloatConst>() -> N {
+        N::PI() / whole(200)
+    }
+}
+
+impl AngularUnit for Turns {
+    #[inline]
+    fn radians_per_unit<N: Float + FloatConst>() -> N {
+        N::PI() * whole(2)
+    }
+}
+
+/// Convert a value from one angular unit to another.
+#[inline]
+pub fn convert<From, To, N>(value: N) -> N
+where
+    From: AngularUnit,
+    To: AngularUnit,
+    N: Float + FloatConst,
+{
+    value * From::radians_per_unit() / To::radians_per_unit()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn close(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1e-12
+    }
+
+    #[test]
+    fn test_convert() {
+        use std::f64::consts::PI;
+
+        assert!(close(convert::<Degrees, Radians, f64>(180.), PI));
+        assert!(close(convert::<Degrees, Gradians, f64>(90.), 100.));
+        assert!(close(convert::<Degrees, Turns, f64>(720.), 2.));
+        assert!(close(convert::<Radians, Degrees, f64>(PI / 2.), 90.));
+        assert!(close(convert::<Radians, Gradians, f64>(PI), 200.));
+        assert!(close(convert::<Radians, Turns, f64>(PI), 0.5));
+        assert!(close(convert::<Gradians, Degrees, f64>(50.), 45.));
+        assert!(close(convert::<Gradians, Radians, f64>(400.), PI * 2.));
+        assert!(close(convert::<Gradians, Turns, f64>(100.), 0.25));
+        assert!(close(convert::<Turns, Degrees, f64>(0.75), 270.));
+        assert!(close(convert::<Turns, Radians, f64>(1.), PI * 2.));
+        assert!(close(convert::<Turns, Gradians, f64>(0.5), 200.));
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let value = 123.456;
+        let gradians = convert::<Degrees, Gradians, f64>(value);
+        let turns = convert::<Gradians, Turns, f64>(gradians);
+        let radians = convert::<Turns, Radians, f64>(turns);
+        assert!(close(convert::<Radians, Degrees, f64>(radians), value));
+    }
+}
@@ -0,0 +1,95 @@
+use crate::{Deg, Rad};
+use num_traits::{
+    cast::FromPrimitive,
+    float::{Float, FloatConst},
+};
+
+/// Angular velocity in degrees per second.
+///
+/// Kept apart from [`Deg`] so a rate can't be mistaken for an angle.
+/// [`integrate`](DegPerSec::integrate) turns it into one over a time step.
+///
+/// ```
+/// use angle::{Deg, DegPerSec};
+///
+/// let turned = DegPerSec(90_f64).integrate(0.5);
+/// assert_eq!(turned, Deg(45.));
+/// ```
+///
+/// ```compile_fail
+/// use angle::{DegPerSec, Rad};
+///
+/// let turned: Rad<f64> = DegPerSec(90_f64).integrate(0.5);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[repr(transparent)]
+pub struct DegPerSec<N: Float>(pub N);
+
+/// Angular velocity in radians per second.
+///
+/// Kept apart from [`Rad`] so a rate can't be mistaken for an angle.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[repr(transparent)]
+pub struct RadPerSec<N: Float>(pub N);
+
+impl<N: Float> DegPerSec<N> {
+    /// Angle turned at this rate over `dt` seconds.
+    #[inline]
+    pub fn integrate(&self, dt: N) -> Deg<N> {
+        Deg(self.0 * dt)
+    }
+}
+
+impl<N: Float> RadPerSec<N> {
+    /// Angle turned at this rate over `dt` seconds.
+    #[inline]
+    pub fn integrate(&self, dt: N) -> Rad<N> {
+        Rad(self.0 * dt)
+    }
+}
+
+impl<N> From<DegPerSec<N>> for RadPerSec<N>
+where
+    N: Float + FromPrimitive + FloatConst,
+{
+    #[inline]
+    fn from(rate: DegPerSec<N>) -> RadPerSec<N> {
+        RadPerSec(Deg(rate.0).to_radians())
+    }
+}
+
+impl<N> From<RadPerSec<N>> for DegPerSec<N>
+where
+    N: Float + FromPrimitive + FloatConst,
+{
+    #[inline]
+    fn from(rate: RadPerSec<N>) -> DegPerSec<N> {
+        DegPerSec(Rad(rate.0).to_degrees())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_integrate() {
+        assert_eq!(DegPerSec(90.).integrate(0.5), Deg(45.));
+        assert_eq!(DegPerSec(-30_f32).integrate(3.), Deg(-90.));
+        assert_eq!(RadPerSec(2.).integrate(0.25), Rad(0.5));
+
+        let mut heading = Deg(0.);
+        let rate = DegPerSec(12.);
+        for _ in 0..10 {
+            heading = Deg(heading.0 + rate.integrate(0.5).0);
+        }
+        assert_eq!(heading, Deg(60.));
+    }
+
+    #[test]
+    fn test_convert() {
+        let rate = RadPerSec::from(DegPerSec(180_f64));
+        assert_eq!(rate, RadPerSec(std::f64::consts::PI));
+        assert_eq!(DegPerSec::from(rate), DegPerSec(180.));
+    }
+}
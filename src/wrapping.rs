@@ -0,0 +1,91 @@
+use crate::Deg;
+use num_traits::{
+    cast::FromPrimitive,
+    float::{Float, FloatConst},
+};
+use std::ops::{Add, AddAssign, Sub, SubAssign};
+
+/// Opt-in modular arithmetic for angles, in the spirit of
+/// `std::num::Wrapping`.
+///
+/// Adding or subtracting wrapped degrees normalizes the result into
+/// [0°, 360°), without changing the meaning of `+` and `-` elsewhere.
+///
+/// ```
+/// use angle::{Deg, Wrapping};
+///
+/// let heading = Wrapping(Deg(350_f64)) + Wrapping(Deg(20.));
+/// assert_eq!(heading.0, Deg(10.));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[repr(transparent)]
+pub struct Wrapping<T>(pub T);
+
+impl<N: Float> From<Deg<N>> for Wrapping<Deg<N>> {
+    #[inline]
+    fn from(deg: Deg<N>) -> Wrapping<Deg<N>> {
+        Wrapping(deg)
+    }
+}
+
+impl<N> Add for Wrapping<Deg<N>>
+where
+    N: Float + FromPrimitive + FloatConst,
+{
+    type Output = Wrapping<Deg<N>>;
+
+    #[inline]
+    fn add(self, rhs: Wrapping<Deg<N>>) -> Wrapping<Deg<N>> {
+        Wrapping(Deg(self.0.value() + rhs.0.value()).normalized())
+    }
+}
+
+impl<N> Sub for Wrapping<Deg<N>>
+where
+    N: Float + FromPrimitive + FloatConst,
+{
+    type Output = Wrapping<Deg<N>>;
+
+    #[inline]
+    fn sub(self, rhs: Wrapping<Deg<N>>) -> Wrapping<Deg<N>> {
+        Wrapping(Deg(self.0.value() - rhs.0.value()).normalized())
+    }
+}
+
+impl<N> AddAssign for Wrapping<Deg<N>>
+where
+    N: Float + FromPrimitive + FloatConst,
+{
+    #[inline]
+    fn add_assign(&mut self, rhs: Wrapping<Deg<N>>) {
+        *self = *self + rhs;
+    }
+}
+
+impl<N> SubAssign for Wrapping<Deg<N>>
+where
+    N: Float + FromPrimitive + FloatConst,
+{
+    #[inline]
+    fn sub_assign(&mut self, rhs: Wrapping<Deg<N>>) {
+        *self = *self - rhs;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_wrapping_arithmetic() {
+        assert_eq!((Wrapping(Deg(350.)) + Wrapping(Deg(20.))).0, Deg(10.));
+        assert_eq!((Wrapping(Deg(10.)) - Wrapping(Deg(20.))).0, Deg(350.));
+        assert_eq!((Wrapping(Deg(180.)) + Wrapping(Deg(180.))).0, Deg(0.));
+
+        let mut heading = Wrapping::from(Deg(300_f32));
+        heading += Wrapping(Deg(90.));
+        assert_eq!(heading.0, Deg(30.));
+        heading -= Wrapping(Deg(45.));
+        assert_eq!(heading.0, Deg(345.));
+    }
+}